@@ -1,3 +1,5 @@
+mod relaunch;
+
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -16,6 +18,8 @@ use utils::RootConfigs;
 use wiring_backend::*;
 use wiring_game_instance::*;
 
+use crate::relaunch::ProvGameInstanceLauncher;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 fn make_host_server_configs(configs: &RootConfigs) -> Result<HostServerStartupPack, String>
@@ -30,7 +34,9 @@ fn make_host_server_configs(configs: &RootConfigs) -> Result<HostServerStartupPa
         max_request_size: configs.get_integer("host_frontend", "LOBBY_LIST_SIZE")?,
         lobby_checker: Box::new(ProvLobbyChecker {
             max_lobby_players: configs.get_integer("lobby", "MAX_LOBBY_PLAYERS")?,
+            max_lobby_watchers: configs.get_integer("lobby", "MAX_LOBBY_WATCHERS")?,
             min_players_to_launch: configs.get_integer("lobby", "MIN_PLAYERS_TO_LAUNCH")?,
+            game_rule_bounds: configs.get_type("lobby", "GAME_RULE_BOUNDS")?,
         }),
     };
     let pending_lobbies_cache_config = PendingLobbiesConfig {
@@ -135,6 +141,7 @@ fn make_test_host_hub_client_with_id(client_id: u128, hub_server_url: url::Url)
 
 fn make_local_game_hub_server(
     game_instance_path: String,
+    max_instance_relaunches: u32,
     hub_server_url: url::Url,
     startup_pack: GameHubServerStartupPack,
     game_factory_config: ProvGameFactoryConfig,
@@ -144,9 +151,10 @@ fn make_local_game_hub_server(
     let (command_sender, command_receiver) = new_channel::<GameHubCommand>();
     let host_hub_client = make_test_host_hub_client_with_id(0u128, hub_server_url);
     let game_launch_pack_source = GameLaunchPackSource::new(ProvGameLaunchPackSource::new(game_factory_config));
-    let game_launcher = GameInstanceLauncher::new(GameInstanceLauncherProcess::new(
+    let game_launcher = GameInstanceLauncher::new(ProvGameInstanceLauncher::new(
         game_instance_path,
         enfync::builtin::native::TokioHandle::adopt_or_default(),
+        max_instance_relaunches,
     ));
 
     // server app
@@ -312,6 +320,9 @@ fn main()
     std::thread::spawn(move || {
         // launch game hub server attached to host server
         let startup_pack = make_hub_server_configs(&configs).unwrap();
+        let max_instance_relaunches = configs
+            .get_integer("game_hub", "MAX_INSTANCE_RELAUNCHES")
+            .unwrap();
         let game_factory_config = ProvGameFactoryConfig {
             local_ip: args.local_ip,
             proxy_ip: args.proxy_ip,
@@ -322,6 +333,7 @@ fn main()
         };
         let (_hub_command_sender, mut hub_server) = make_local_game_hub_server(
             args.game_instance_path,
+            max_instance_relaunches,
             hub_server_url,
             startup_pack,
             game_factory_config,
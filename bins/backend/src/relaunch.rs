@@ -0,0 +1,234 @@
+use std::time::Duration;
+
+use bevy_girk_backend_public::*;
+use bevy_girk_game_instance::*;
+use bevy_girk_utils::*;
+use enfync::Handle;
+use wiring_game_instance::LaunchData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How often a supervised game instance is polled for reports and exit.
+const INSTANCE_POLL_PERIOD: Duration = Duration::from_millis(100);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a launch pack that resumes a game from its latest snapshot.
+fn resume_launch_pack(launch_pack: &GameLaunchPack) -> Result<GameLaunchPack, String>
+{
+    let mut data = deser_msg::<LaunchData>(&launch_pack.game_launch_data)
+        .ok_or_else(|| format!("failed deserializing launch data for game {}", launch_pack.game_id))?;
+    data.resume = true;
+    Ok(GameLaunchPack::new(launch_pack.game_id, data))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the reports of one game instance process.
+#[derive(Default)]
+struct InstanceReports
+{
+    started: bool,
+    ended: bool,
+    /// Reports that are only forwarded if the instance won't be relaunched (e.g. abort reports).
+    held: Vec<GameInstanceReport>,
+}
+
+impl InstanceReports
+{
+    fn forward(&mut self, reports: &IoReceiver<GameInstanceReport>, report_sender: &IoSender<GameInstanceReport>)
+    {
+        while let Some(report) = reports.try_recv() {
+            match report {
+                GameInstanceReport::GameStart(..) => {
+                    self.started = true;
+                    let _ = report_sender.send(report);
+                }
+                GameInstanceReport::GameOver(..) => {
+                    self.ended = true;
+                    let _ = report_sender.send(report);
+                }
+                report => self.held.push(report),
+            }
+        }
+    }
+
+    /// Forwards held reports once the instance won't be relaunched.
+    fn release_held(&mut self, report_sender: &IoSender<GameInstanceReport>)
+    {
+        for report in self.held.drain(..) {
+            let _ = report_sender.send(report);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Decides if a game should be relaunched after its instance exited.
+///
+/// Only games that were running when their instance died are relaunched.
+fn can_relaunch(
+    success: bool,
+    reports: &InstanceReports,
+    aborted: bool,
+    relaunches: u32,
+    max_relaunches: u32,
+) -> bool
+{
+    !success && reports.started && !reports.ended && !aborted && relaunches < max_relaunches
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs a game instance process, relaunching it from the game's latest snapshot if it dies mid-game.
+///
+/// Returns the result of the last instance process.
+async fn supervise_instance(
+    launcher: GameInstanceLauncherProcess,
+    mut launch_pack: GameLaunchPack,
+    report_sender: IoSender<GameInstanceReport>,
+    command_receiver: IoReceiver<GameInstanceCommand>,
+    max_relaunches: u32,
+) -> bool
+{
+    let game_id = launch_pack.game_id;
+    let mut relaunches = 0;
+    let mut aborted = false;
+
+    loop {
+        let (instance_report_sender, instance_reports) = new_io_channel::<GameInstanceReport>();
+        let mut instance = launcher.launch(launch_pack.clone(), instance_report_sender);
+        let mut reports = InstanceReports::default();
+
+        while instance.is_running() {
+            while let Some(command) = command_receiver.try_recv() {
+                aborted |= matches!(command, GameInstanceCommand::Abort);
+                let _ = instance.send_command(command);
+            }
+            reports.forward(&instance_reports, &report_sender);
+            tokio::time::sleep(INSTANCE_POLL_PERIOD).await;
+        }
+        let success = instance.get().await;
+        reports.forward(&instance_reports, &report_sender);
+        while let Some(command) = command_receiver.try_recv() {
+            aborted |= matches!(command, GameInstanceCommand::Abort);
+        }
+
+        let relaunch_pack = match can_relaunch(success, &reports, aborted, relaunches, max_relaunches) {
+            true => resume_launch_pack(&launch_pack)
+                .inspect_err(|err| tracing::error!("failed relaunching game {game_id}: {err}"))
+                .ok(),
+            false => None,
+        };
+        let Some(relaunch_pack) = relaunch_pack else {
+            reports.release_held(&report_sender);
+            return success;
+        };
+
+        relaunches += 1;
+        tracing::warn!(game_id, relaunches, "game instance died mid-game, relaunching from its latest snapshot");
+        launch_pack = relaunch_pack;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Launches game instance processes for the game hub, relaunching instances that die mid-game.
+///
+/// Reports and commands are forwarded between the hub and the current instance process. If an instance exits with
+/// an error after its game started, and before the game ended or the hub aborted it, a new instance is launched
+/// with [`LaunchData::resume`] set so the game continues from its latest snapshot. The new instance's start report
+/// is forwarded to the hub so clients can reconnect.
+pub(crate) struct ProvGameInstanceLauncher
+{
+    game_instance_path: String,
+    spawner: enfync::builtin::native::TokioHandle,
+    max_relaunches: u32,
+}
+
+impl ProvGameInstanceLauncher
+{
+    pub(crate) fn new(
+        game_instance_path: String,
+        spawner: enfync::builtin::native::TokioHandle,
+        max_relaunches: u32,
+    ) -> Self
+    {
+        Self { game_instance_path, spawner, max_relaunches }
+    }
+}
+
+impl GameInstanceLauncherImpl for ProvGameInstanceLauncher
+{
+    fn launch(&self, launch_pack: GameLaunchPack, report_sender: IoSender<GameInstanceReport>) -> GameInstance
+    {
+        let game_id = launch_pack.game_id;
+        let launcher = GameInstanceLauncherProcess::new(self.game_instance_path.clone(), self.spawner.clone());
+        let (command_sender, command_receiver) = new_io_channel::<GameInstanceCommand>();
+        let instance = self.spawner.spawn(supervise_instance(
+            launcher,
+            launch_pack,
+            report_sender,
+            command_receiver,
+            self.max_relaunches,
+        ));
+
+        GameInstance::new(game_id, command_sender, instance)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn running() -> InstanceReports
+    {
+        InstanceReports { started: true, ..Default::default() }
+    }
+
+    #[test]
+    fn relaunches_games_that_died_mid_game()
+    {
+        assert!(can_relaunch(false, &running(), false, 0, 3));
+        assert!(can_relaunch(false, &running(), false, 2, 3));
+    }
+
+    #[test]
+    fn does_not_relaunch_finished_or_unstarted_games()
+    {
+        // The instance exited cleanly.
+        assert!(!can_relaunch(true, &running(), false, 0, 3));
+        // The game never started.
+        assert!(!can_relaunch(false, &InstanceReports::default(), false, 0, 3));
+        // The game already ended.
+        let ended = InstanceReports { started: true, ended: true, ..Default::default() };
+        assert!(!can_relaunch(false, &ended, false, 0, 3));
+        // The hub aborted the game.
+        assert!(!can_relaunch(false, &running(), true, 0, 3));
+        // Out of relaunches.
+        assert!(!can_relaunch(false, &running(), false, 3, 3));
+    }
+
+    #[test]
+    fn abort_reports_are_held_until_released()
+    {
+        let (instance_sender, instance_reports) = new_io_channel::<GameInstanceReport>();
+        let (report_sender, hub_reports) = new_io_channel::<GameInstanceReport>();
+
+        let mut reports = running();
+        let _ = instance_sender.send(GameInstanceReport::GameAborted(1, "instance died".into()));
+        reports.forward(&instance_reports, &report_sender);
+        assert_eq!(reports.held.len(), 1);
+        assert!(hub_reports.try_recv().is_none());
+
+        // Held reports are only forwarded when the game won't be relaunched.
+        reports.release_held(&report_sender);
+        assert!(matches!(hub_reports.try_recv(), Some(GameInstanceReport::GameAborted(1, _))));
+        assert!(hub_reports.try_recv().is_none());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
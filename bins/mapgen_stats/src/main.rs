@@ -0,0 +1,133 @@
+//! Reports statistics about generated maps across many seeds.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::Parser;
+use game_core::*;
+use utils::RootConfigs;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Parser, Debug)]
+struct MapgenStatsCli
+{
+    /// Number of seeds to generate maps for (defaults to 1000).
+    #[arg(long)]
+    seeds: Option<u64>,
+    /// Location of config files.
+    #[arg(long)]
+    config_dir: Option<String>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct TileStats
+{
+    /// Expected fraction of non-edge tiles based on mapgen factors.
+    expected: f64,
+    /// Total count across all maps.
+    total: u64,
+    /// Smallest fraction of non-edge tiles in one map.
+    min_fraction: Option<f64>,
+    /// Largest fraction of non-edge tiles in one map.
+    max_fraction: f64,
+    /// Configured min same-tile distance.
+    min_same_tile_distance: Option<u32>,
+    /// Smallest distance between two tiles of this type in one map.
+    closest: Option<u32>,
+    /// Number of tile pairs closer than the min same-tile distance.
+    violations: u64,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn main()
+{
+    let args = MapgenStatsCli::parse();
+    let num_seeds = args.seeds.unwrap_or(1000).max(1);
+    let config_dir: PathBuf = args
+        .config_dir
+        .unwrap_or_else(|| DEFAULT_CONFIG_DIR.into())
+        .into();
+
+    let configs = RootConfigs::new(&config_dir, &["game"]).unwrap();
+    let data = GameData::new(&configs).unwrap();
+
+    let factor_total: u64 = data.tiles.values().map(|spec| spec.mapgen_factor).sum();
+    let mut stats: BTreeMap<String, TileStats> = data
+        .tiles
+        .iter()
+        .map(|(id, spec)| {
+            let stats = TileStats {
+                expected: spec.mapgen_factor as f64 / factor_total.max(1) as f64,
+                min_same_tile_distance: spec.min_same_tile_distance,
+                ..Default::default()
+            };
+            (id.get().to_string(), stats)
+        })
+        .collect();
+
+    let mut total_tiles = 0;
+    for seed in 0..num_seeds {
+        let tiles = generate_map_tiles(map_gen_prng(seed as u128), &data.mapgen_settings, &data.tiles);
+        let interior: Vec<&GeneratedTile> = tiles.iter().filter(|tile| !tile.is_edge).collect();
+        total_tiles += interior.len() as u64;
+
+        for (id, stats) in stats.iter_mut() {
+            let positions: Vec<_> = interior
+                .iter()
+                .filter(|tile| tile.id.get() == id)
+                .map(|tile| tile.coord)
+                .collect();
+
+            let fraction = positions.len() as f64 / interior.len().max(1) as f64;
+            stats.total += positions.len() as u64;
+            stats.min_fraction = Some(stats.min_fraction.map_or(fraction, |min| min.min(fraction)));
+            stats.max_fraction = stats.max_fraction.max(fraction);
+
+            // Spacing is only checked for tiles that are expected to be rare.
+            let Some(min_distance) = stats.min_same_tile_distance else { continue };
+            for (i, a) in positions.iter().enumerate() {
+                for b in positions.iter().skip(i + 1) {
+                    let distance = a.unsigned_distance_to(*b);
+                    stats.closest = Some(
+                        stats
+                            .closest
+                            .map_or(distance, |closest| closest.min(distance)),
+                    );
+                    if distance < min_distance {
+                        stats.violations += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!("mapgen stats: {num_seeds} seeds, terrain mode {:?}", data.mapgen_settings.terrain_mode);
+    println!(
+        "{:<12} {:>9} {:>9} {:>9} {:>9} {:>9} {:>9} {:>10}",
+        "tile", "expected", "mean", "min", "max", "min dist", "closest", "violations"
+    );
+    for (id, stats) in stats.iter() {
+        let optional = |value: Option<u32>| value.map_or("-".to_string(), |value| value.to_string());
+        println!(
+            "{:<12} {:>8.2}% {:>8.2}% {:>8.2}% {:>8.2}% {:>9} {:>9} {:>10}",
+            id,
+            stats.expected * 100.,
+            stats.total as f64 / total_tiles.max(1) as f64 * 100.,
+            stats.min_fraction.unwrap_or_default() * 100.,
+            stats.max_fraction * 100.,
+            optional(stats.min_same_tile_distance),
+            optional(stats.closest),
+            stats.violations,
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const DEFAULT_CONFIG_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../config");
+
+//-------------------------------------------------------------------------------------------------------------------
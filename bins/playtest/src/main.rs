@@ -4,6 +4,7 @@ use bevy_girk_game_instance::*;
 use bevy_girk_utils::*;
 use clap::Parser;
 use enfync::{AdoptOrDefault, Handle};
+use game_core::BotDifficulty;
 use renet2_setup::ConnectionType;
 use wiring_backend::*;
 use wiring_game_instance::*;
@@ -16,6 +17,12 @@ struct PlaytestCli
     /// Specify the number of clients (defaults to 1, minimum is 1).
     #[arg(long)]
     clients: Option<usize>,
+    /// Specify the number of bots (defaults to 0).
+    #[arg(long)]
+    bots: Option<usize>,
+    /// Specify the bot difficulty (defaults to normal).
+    #[arg(long, value_parser = parse_bot_difficulty)]
+    bot_difficulty: Option<BotDifficulty>,
     /// Specify the location of the game instance binary (will use the debug build directory by default).
     game: Option<String>,
     /// Specify the location of the game client binary (will use the debug build directory by default).
@@ -27,6 +34,7 @@ impl PlaytestCli
     fn extract(self) -> PlaytestCliResolved
     {
         let num_clients = self.clients.unwrap_or(1usize).max(1usize);
+        let bots = vec![self.bot_difficulty.unwrap_or_default(); self.bots.unwrap_or_default()];
         let game_instance_path = self
             .game
             .unwrap_or_else(|| String::from(GAME_INSTANCE_PATH));
@@ -34,13 +42,24 @@ impl PlaytestCli
             .client
             .unwrap_or_else(|| String::from(GAME_CLIENT_PATH));
 
-        PlaytestCliResolved { num_clients, game_instance_path, game_client_path }
+        PlaytestCliResolved { num_clients, bots, game_instance_path, game_client_path }
+    }
+}
+
+fn parse_bot_difficulty(arg: &str) -> Result<BotDifficulty, String>
+{
+    match arg {
+        "easy" => Ok(BotDifficulty::Easy),
+        "normal" => Ok(BotDifficulty::Normal),
+        "hard" => Ok(BotDifficulty::Hard),
+        _ => Err(format!("unknown bot difficulty {arg:?}; expected easy, normal, or hard")),
     }
 }
 
 struct PlaytestCliResolved
 {
     num_clients: usize,
+    bots: Vec<BotDifficulty>,
     game_instance_path: String,
     game_client_path: String,
 }
@@ -196,8 +215,14 @@ fn main()
     let lobby_contents = ProvLobbyContents {
         id: 0u64,
         owner_id: 0u128,
-        config: ProvLobbyConfig { max_players: args.num_clients as u16 },
+        config: ProvLobbyConfig {
+            max_players: (args.num_clients + args.bots.len()) as u16,
+            max_watchers: 0,
+            bots: args.bots,
+            rules: GameRuleOverrides::default(),
+        },
         players,
+        watchers: Vec::default(),
     };
 
     // launch pack
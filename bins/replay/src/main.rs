@@ -0,0 +1,107 @@
+//! Plays back a replay file and verifies the game outcome.
+
+use std::time::Duration;
+
+use clap::Parser;
+use game_core::*;
+use game_harness::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Parser, Debug)]
+struct ReplayCli
+{
+    /// Location of the replay file.
+    file: String,
+    /// Ticks per second to simulate with (defaults to 20).
+    #[arg(long)]
+    tps: Option<u32>,
+    /// Maximum app time to simulate after the last request, in seconds (defaults to 14400).
+    #[arg(long)]
+    max_secs: Option<u64>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks the parts of a game over report that are deterministic.
+///
+/// The game duration is excluded because it depends on frame timing.
+fn compare_reports(recorded: &ProvGameOverReport, replayed: &ProvGameOverReport) -> Result<(), String>
+{
+    if recorded.game_id != replayed.game_id {
+        return Err(format!("game id mismatch: recorded {}, replayed {}", recorded.game_id, replayed.game_id));
+    }
+    if recorded.rounds != replayed.rounds {
+        return Err(format!("rounds mismatch: recorded {}, replayed {}", recorded.rounds, replayed.rounds));
+    }
+    if recorded.player_reports != replayed.player_reports {
+        return Err(format!(
+            "player reports mismatch:\nrecorded {:?}\nreplayed {:?}",
+            recorded.player_reports, replayed.player_reports
+        ));
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn run(args: ReplayCli) -> Result<(), String>
+{
+    let json =
+        std::fs::read_to_string(&args.file).map_err(|err| format!("failed reading {}: {err:?}", args.file))?;
+    let replay = Replay::from_json(&json)?;
+    replay.validate()?;
+    if replay.used_commands {
+        tracing::warn!("replay used command inputs, which are not recorded; playback may not match");
+    }
+
+    let mut game_data = replay.game_data.clone();
+    game_data.replay_settings.enabled = false;
+    game_data.rate_limit_settings.enabled = false;
+    let mut harness = GameHarness::new(replay.initializer(), game_data, args.tps.unwrap_or(20));
+
+    for request in replay.requests.iter() {
+        harness.advance_to(request.time);
+        if harness.game_over_report().is_some() {
+            return Err(
+                format!("game ended before request at {:?} from client {}", request.time, request.client_id),
+            );
+        }
+        harness.submit(request.client_id, request.request.clone());
+    }
+
+    let max_duration = Duration::from_secs(args.max_secs.unwrap_or(14400));
+    let Some(report) = harness.run_to_end(max_duration).cloned() else {
+        return Err(format!("game did not end within {max_duration:?}"));
+    };
+    compare_reports(&replay.game_over_report, &report)?;
+    let end_state = GameEndState::from_world(harness.world_mut());
+    if let Some(diff) = replay.end_state.diff(&end_state) {
+        return Err(format!("end state mismatch (recorded vs replayed): {diff}"));
+    }
+
+    tracing::info!(requests = replay.requests.len(), "replay matches");
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn main()
+{
+    let filter = tracing_subscriber::EnvFilter::builder()
+        .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+        .from_env()
+        .unwrap();
+    tracing_subscriber::FmtSubscriber::builder()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+
+    if let Err(err) = run(ReplayCli::parse()) {
+        tracing::error!("{err}");
+        std::process::exit(1);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
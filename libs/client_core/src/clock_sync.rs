@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_renet2::prelude::RenetClient;
+use utils::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates the latency estimate from the renet client's round-trip time.
+///
+/// The renet client smooths its own RTT samples, so they are used as-is.
+fn update_server_latency(client: Option<Res<RenetClient>>, mut latency: ResMut<ServerLatency>)
+{
+    let Some(client) = client else { return };
+    latency.rtt = Duration::from_secs_f64(client.rtt().max(0.));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Estimated latency to the game server.
+///
+/// Used to compensate for network delay when setting timers from server messages.
+#[derive(Resource, Default, Debug)]
+pub struct ServerLatency
+{
+    rtt: Duration,
+    /// Game time of the most recent clock sync.
+    last_sync_ms: u128,
+}
+
+impl ServerLatency
+{
+    /// Round-trip time to the server.
+    pub fn rtt(&self) -> Duration
+    {
+        self.rtt
+    }
+
+    /// Estimated time for a message to travel from the server to the client.
+    pub fn one_way(&self) -> Duration
+    {
+        self.rtt / 2
+    }
+
+    /// Subtracts the one-way latency from a remaining time sent by the server.
+    pub(crate) fn compensate(&self, remaining_ms: u128) -> u128
+    {
+        remaining_ms.saturating_sub(self.one_way().as_millis())
+    }
+
+    /// Records the game time of a clock sync. Returns `false` if the sync is older than the latest one.
+    pub(crate) fn try_record_sync(&mut self, game_time_ms: u128) -> bool
+    {
+        if game_time_ms < self.last_sync_ms {
+            return false;
+        }
+        self.last_sync_ms = game_time_ms;
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ClockSyncPlugin;
+
+impl Plugin for ClockSyncPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.reinit_resource_on_enter::<ServerLatency>(ClientAppState::Game)
+            .add_systems(Update, update_server_latency.in_set(ClientLogicSet::Admin));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
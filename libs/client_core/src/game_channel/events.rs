@@ -0,0 +1,33 @@
+use game_core::{ClientRequest, RejectionReason, ResourceId, TileClaimResult};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcast when the game rejects a request sent by this client.
+#[derive(Debug, Clone)]
+pub struct RequestRejected
+{
+    pub request: ClientRequest,
+    pub reason: RejectionReason,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcast when tile claims are resolved at the end of a round.
+#[derive(Debug, Clone)]
+pub struct TileClaimsResolved
+{
+    pub round: u32,
+    pub results: Vec<TileClaimResult>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive event broadcast when the game reports this client's production at the end of a round.
+#[derive(Debug, Clone)]
+pub struct ProductionReceived
+{
+    pub round: u32,
+    pub produced: Vec<(ResourceId, u64)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
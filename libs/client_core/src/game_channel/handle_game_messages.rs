@@ -8,9 +8,41 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn handle_request_rejected(request: ClientRequest, reason: RejectionReason)
+fn handle_request_rejected(In((request, reason)): In<(ClientRequest, RejectionReason)>, mut c: Commands)
 {
     tracing::warn!("game request {request:?} rejected: {reason:?}");
+    c.react().broadcast(RequestRejected { request, reason });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_tile_claim_results(In((round, results)): In<(u32, Vec<TileClaimResult>)>, mut c: Commands)
+{
+    for result in results.iter() {
+        tracing::info!("tile claim resolved in round {round}: {result:?}");
+    }
+    c.react().broadcast(TileClaimsResolved { round, results });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_production_summary(In((round, produced)): In<(u32, Vec<(ResourceId, u64)>)>, mut c: Commands)
+{
+    tracing::info!("produced in round {round}: {produced:?}");
+    c.react().broadcast(ProductionReceived { round, produced });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_order_results(In((round, results)): In<(u32, Vec<OrderResult>)>, mut c: Commands)
+{
+    for OrderResult { order, result } in results {
+        tracing::info!("queued order resolved in round {round}: {order:?} {result:?}");
+        if let Err(reason) = result {
+            c.react()
+                .broadcast(RequestRejected { request: ClientRequest::PlayerInput(order), reason });
+        }
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -22,13 +54,29 @@ pub(crate) fn handle_game_message(world: &mut World, _tick: Tick, message: GameM
 {
     let _state = **world.resource::<State<ClientState>>();
 
+    handle_game_message_inner(world, message);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) fn handle_game_message_inner(world: &mut World, message: GameMsg)
+{
     match message {
-        GameMsg::RequestRejected { reason, request } => handle_request_rejected(request, reason),
+        GameMsg::RequestRejected { reason, request } => world.syscall((request, reason), handle_request_rejected),
         GameMsg::CurrentGameState(game_state) => world.syscall(game_state, handle_game_state),
         GameMsg::TileSelectInfo { remaining_ms } => world.syscall(remaining_ms, handle_tile_select_info),
         GameMsg::RoundInfo { round, remaining_ms } => world.syscall((round, remaining_ms), handle_round_info),
+        GameMsg::TileClaimResults { round, results } => world.syscall((round, results), handle_tile_claim_results),
+        GameMsg::ProductionSummary { round, produced } => {
+            world.syscall((round, produced), handle_production_summary)
+        }
+        GameMsg::OrderResults { round, results } => world.syscall((round, results), handle_order_results),
+        GameMsg::ClockSync { game_time_ms, round, remaining_ms } => {
+            world.syscall((game_time_ms, round, remaining_ms), handle_clock_sync)
+        }
         GameMsg::Pause => world.syscall((), handle_pause),
         GameMsg::Unpause => world.syscall((), handle_unpause),
+        GameMsg::Resync(resync) => world.syscall(resync, handle_resync),
     }
 }
 
@@ -4,6 +4,7 @@ use bevy_girk_client_fw::*;
 use bevy_girk_utils::apply_state_transitions;
 use game_core::*;
 
+use super::*;
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -47,16 +48,45 @@ pub(super) fn handle_game_state(In(current_game_state): In<GameState>, world: &m
 
 //-------------------------------------------------------------------------------------------------------------------
 
-pub(super) fn handle_tile_select_info(In(remaining_ms): In<u128>, mut select_timer: ResMut<TileSelectTimer>)
+pub(super) fn handle_tile_select_info(
+    In(remaining_ms): In<u128>,
+    latency: Res<ServerLatency>,
+    mut select_timer: ResMut<TileSelectTimer>,
+)
 {
-    select_timer.set(remaining_ms);
+    select_timer.set(latency.compensate(remaining_ms));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-pub(super) fn handle_round_info(In((round, remaining_ms)): In<(u32, u128)>, mut round_timer: ResMut<RoundTimer>)
+pub(super) fn handle_round_info(
+    In((round, remaining_ms)): In<(u32, u128)>,
+    latency: Res<ServerLatency>,
+    mut round_timer: ResMut<RoundTimer>,
+)
 {
-    round_timer.set(round, remaining_ms);
+    round_timer.set(round, latency.compensate(remaining_ms));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) fn handle_clock_sync(
+    In((game_time_ms, round, remaining_ms)): In<(u128, u32, u128)>,
+    mut latency: ResMut<ServerLatency>,
+    mut select_timer: ResMut<TileSelectTimer>,
+    mut round_timer: ResMut<RoundTimer>,
+)
+{
+    if !latency.try_record_sync(game_time_ms) {
+        tracing::trace!("ignoring stale clock sync at {game_time_ms}ms");
+        return;
+    }
+
+    let remaining_ms = latency.compensate(remaining_ms);
+    match round {
+        0 => select_timer.sync(remaining_ms),
+        _ => round_timer.sync(round, remaining_ms),
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -76,3 +106,31 @@ pub(super) fn handle_unpause(mut select_timer: ResMut<TileSelectTimer>, mut roun
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Rebuilds the client's game state after connecting or reconnecting.
+pub(super) fn handle_resync(In(resync): In<GameResync>, world: &mut World)
+{
+    tracing::info!(
+        "resyncing game state {:?} in round {} with {} pending notifications",
+        resync.state,
+        resync.round,
+        resync.notifications.len()
+    );
+
+    match resync.state {
+        GameState::TileSelect => world.syscall(resync.remaining_ms, handle_tile_select_info),
+        GameState::Play => world.syscall((resync.round, resync.remaining_ms), handle_round_info),
+        GameState::Startup | GameState::Init | GameState::End => (),
+    }
+    match resync.paused {
+        true => world.syscall((), handle_pause),
+        false => world.syscall((), handle_unpause),
+    }
+    world.syscall(resync.state, handle_game_state);
+
+    for notification in resync.notifications {
+        handle_game_message_inner(world, notification);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,5 +1,7 @@
+mod events;
 mod handle_game_messages;
 mod handle_game_messages_impl;
 
+pub use events::*;
 pub(crate) use handle_game_messages::*;
 pub(self) use handle_game_messages_impl::*;
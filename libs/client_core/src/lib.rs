@@ -1,3 +1,4 @@
+mod clock_sync;
 mod game_channel;
 mod game_end;
 mod mapgen;
@@ -9,7 +10,8 @@ mod setup;
 mod states;
 
 pub use bevy_girk_client_fw::ClientAppState;
-pub(crate) use game_channel::*;
+pub use clock_sync::*;
+pub use game_channel::*;
 pub(crate) use game_end::*;
 pub use mapgen::*;
 pub use player_inputs::*;
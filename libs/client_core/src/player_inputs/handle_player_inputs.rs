@@ -27,7 +27,7 @@ pub(crate) fn handle_player_inputs(world: &mut World)
     let ctx = world.resource::<ClientContext>();
     let client_id = ctx.client_id;
     let is_player = ctx.client_type == ClientType::Player;
-    let is_playstate = state == ClientState::Play;
+    let is_inputstate = matches!(state, ClientState::TileSelect | ClientState::Play);
 
     let Some(inputs) = world.remove_resource::<Receiver<PlayerInput>>() else {
         return;
@@ -38,7 +38,7 @@ pub(crate) fn handle_player_inputs(world: &mut World)
             tracing::warn!("ignoring input sent by non-player client {client_id}: {input:?}");
             continue;
         }
-        if !is_playstate {
+        if !is_inputstate {
             tracing::warn!("ignoring invalid input sent during {state:?}: {input:?}");
             continue;
         }
@@ -25,6 +25,7 @@ impl Plugin for ClientCorePlugin
             .add_plugins(ClientSetupPlugin)
             .add_plugins(PlayerInputPlugin)
             .add_plugins(RoundsPlugin)
+            .add_plugins(ClockSyncPlugin)
             .add_plugins(GameEndPlugin)
             .add_plugins(MapgenPlugin)
             .add_systems(OnEnter(ClientInitState::Done), request_game_state)
@@ -15,6 +15,30 @@ fn time_now() -> Duration
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Timer corrections larger than this are applied immediately instead of being smoothed.
+const MAX_SMOOTHED_CORRECTION: Duration = Duration::from_millis(500);
+
+/// Fraction of a smoothed timer correction applied for each clock sync.
+const CORRECTION_FACTOR: f64 = 0.5;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Moves a timer's end time toward the end time estimated from a clock sync.
+///
+/// Small corrections are smoothed so latency jitter doesn't make the countdown jump around.
+fn corrected_end_time(current: Duration, target: Duration) -> Duration
+{
+    if current.abs_diff(target) > MAX_SMOOTHED_CORRECTION {
+        return target;
+    }
+    match target > current {
+        true => current.saturating_add((target - current).mul_f64(CORRECTION_FACTOR)),
+        false => current.saturating_sub((current - target).mul_f64(CORRECTION_FACTOR)),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Resource, Default, Debug)]
 pub struct TileSelectTimer
 {
@@ -37,6 +61,16 @@ impl TileSelectTimer
         self.pause_start_time = now;
     }
 
+    /// Corrects drift using a clock sync from the server. Ignored while paused.
+    pub(crate) fn sync(&mut self, remaining_ms: u128)
+    {
+        if self.paused {
+            return;
+        }
+        let target = time_now().saturating_add(Duration::from_millis(remaining_ms as u64));
+        self.end_time = corrected_end_time(self.end_time, target);
+    }
+
     pub(crate) fn pause(&mut self)
     {
         if self.paused {
@@ -97,6 +131,23 @@ impl RoundTimer
         self.pause_start_time = now;
     }
 
+    /// Corrects drift using a clock sync from the server. Ignored while paused.
+    ///
+    /// Clock syncs are unordered, so syncs for earlier rounds are ignored. The timer is reset if the round moved
+    /// forward.
+    pub(crate) fn sync(&mut self, round: u32, remaining_ms: u128)
+    {
+        if self.paused || round < self.round {
+            return;
+        }
+        if round > self.round {
+            self.set(round, remaining_ms);
+            return;
+        }
+        let target = time_now().saturating_add(Duration::from_millis(remaining_ms as u64));
+        self.round_end_time = corrected_end_time(self.round_end_time, target);
+    }
+
     pub(crate) fn pause(&mut self)
     {
         if self.paused {
@@ -155,3 +206,74 @@ impl Plugin for RoundsPlugin
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn millis(millis: u64) -> Duration
+    {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn small_corrections_are_smoothed()
+    {
+        let current = millis(10_000);
+        assert_eq!(corrected_end_time(current, millis(10_100)), millis(10_050));
+        assert_eq!(corrected_end_time(current, millis(9_900)), millis(9_950));
+        assert_eq!(corrected_end_time(current, current), current);
+
+        // Repeated syncs converge on the target.
+        let mut end_time = current;
+        for _ in 0..10 {
+            end_time = corrected_end_time(end_time, millis(10_400));
+        }
+        assert!(end_time.abs_diff(millis(10_400)) < millis(1));
+    }
+
+    #[test]
+    fn large_corrections_are_immediate()
+    {
+        let current = millis(10_000);
+        assert_eq!(corrected_end_time(current, millis(11_000)), millis(11_000));
+        assert_eq!(corrected_end_time(current, millis(9_000)), millis(9_000));
+    }
+
+    #[test]
+    fn round_sync_ignores_earlier_rounds()
+    {
+        let mut timer = RoundTimer::default();
+        timer.set(2, 20_000);
+
+        // A late sync from the previous round doesn't roll the timer back.
+        timer.sync(1, 50);
+        assert_eq!(timer.round(), 2);
+        assert!(timer.remaining_time() > millis(19_000));
+
+        // A sync for a later round resets the timer.
+        timer.sync(3, 5_000);
+        assert_eq!(timer.round(), 3);
+        assert!(timer.remaining_time() <= millis(5_000));
+        assert!(timer.remaining_time() > millis(4_000));
+
+        // A sync for the current round only nudges the timer.
+        timer.sync(3, 4_800);
+        assert!(timer.remaining_time() <= millis(4_900));
+        assert!(timer.remaining_time() > millis(4_000));
+    }
+
+    #[test]
+    fn round_sync_ignored_while_paused()
+    {
+        let mut timer = RoundTimer::default();
+        timer.set(1, 20_000);
+        timer.pause();
+        timer.sync(2, 5_000);
+        assert_eq!(timer.round(), 1);
+        assert!(timer.remaining_time() > millis(19_000));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -43,6 +43,11 @@ pub(crate) fn setup_client(world: &mut World)
     let initializer = world
         .remove_resource::<ClientInitializer>()
         .expect("initializer missing");
+
+    // the lobby may override the map size
+    if let Some(mut settings) = world.get_resource_mut::<MapGenSettings>() {
+        settings.map_dimension = initializer.context.map_dimension;
+    }
     world.insert_resource(initializer.context);
 }
 
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use game_core::*;
+
+use super::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reveals tiles that have entered the player's sight.
+///
+/// Tiles stay explored after leaving sight, but their metas will no longer be replicated.
+fn reveal_explored_tiles(
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    sights: Query<Ref<PlayerSight>>,
+    new_unexplored: Query<(), Added<Unexplored>>,
+    mut tiles: Query<(&mut Sprite, Has<TilePressed>), (With<MapTile>, With<Unexplored>)>,
+)
+{
+    for sight in sights.iter() {
+        if !sight.is_changed() && new_unexplored.is_empty() {
+            continue;
+        }
+
+        for tile in sight.tiles().iter() {
+            let Some(tile_entity) = grid.tiles.get(tile).copied() else { continue };
+            let Ok((mut sprite, is_pressed)) = tiles.get_mut(tile_entity) else { continue };
+            if !is_pressed {
+                sprite.color = Color::WHITE;
+            }
+            c.entity(tile_entity).remove::<Unexplored>();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component for map tiles the player has never seen while fog of war is enabled.
+#[derive(Component, Debug)]
+pub(crate) struct Unexplored;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct MapFogPlugin;
+
+impl Plugin for MapFogPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            Update,
+            reveal_explored_tiles.run_if(|fog: Option<Res<FogOfWarSettings>>| fog.is_some_and(|fog| fog.enabled)),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -26,12 +26,16 @@ fn handle_new_tile_pressed(
 
 fn handle_tile_unpressed(
     event: Trigger<OnRemove, TilePressed>,
-    mut tiles: Query<(&mut Sprite, Option<&AttachedMeta>), With<MapTile>>,
+    map_settings: Res<MapSettings>,
+    mut tiles: Query<(&mut Sprite, Option<&AttachedMeta>, Has<Unexplored>), With<MapTile>>,
     mut attached: Query<&mut Sprite, Without<MapTile>>,
 )
 {
-    let Ok((mut sprite, maybe_attachment)) = tiles.get_mut(event.target()) else { return };
-    sprite.color = Color::WHITE;
+    let Ok((mut sprite, maybe_attachment, is_unexplored)) = tiles.get_mut(event.target()) else { return };
+    sprite.color = match is_unexplored {
+        true => map_settings.fog_color,
+        false => Color::WHITE,
+    };
 
     let Some(attachment) = maybe_attachment else { return };
     let Ok(mut sprite) = attached.get_mut(**attachment) else { return };
@@ -3,7 +3,6 @@ use bevy_aseprite_ultra::prelude::AseSlice;
 use bevy_cobweb::prelude::*;
 use client_core::MapGenerated;
 use game_core::*;
-use hexx::Hex;
 use utils_gui::AsepriteMap;
 
 use crate::*;
@@ -42,11 +41,16 @@ fn update_map_tiles(
     aseprites: Res<AsepriteMap>,
     grid: Res<HexGrid>,
     settings: Res<MapSettings>,
+    fog: Res<FogOfWarSettings>,
     tiles: Query<(Entity, &TileId), With<MapTile>>,
 )
 {
     let aseprite = aseprites.get(&settings.tile_aseprite);
     let sprite_size = grid.layout.rect_size();
+    let color = match fog.enabled {
+        true => settings.fog_color,
+        false => Color::WHITE,
+    };
 
     for (entity, tile_id) in tiles.iter() {
         let Ok(mut ec) = c.get_entity(entity) else { continue };
@@ -56,26 +60,34 @@ fn update_map_tiles(
                 aseprite: aseprite.clone(),
                 name: info.aseprite_slice.clone(),
             },
-            Sprite { custom_size: Some(sprite_size), ..default() },
+            Sprite { custom_size: Some(sprite_size), color, ..default() },
             Pickable::default(),
         ));
+        if fog.enabled {
+            ec.insert(Unexplored);
+        }
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+// Uses the bounding box of all tile centers, so the boundary follows the map shape.
 // Includes an offset upwards by half a hex so more edge tiles show at top than bottom.
 fn set_camera_boundary(mut c: Commands, grid: Res<HexGrid>)
 {
-    let mut upper_right = grid.layout.hex_to_world_pos(Hex {
-        x: grid.dimension,
-        y: (grid.dimension / 2) + (grid.dimension % 2),
-    });
-    upper_right.y += grid.layout.rect_size().y / 2.0;
+    let mut upper_right = Vec2::MIN;
+    let mut lower_left = Vec2::MAX;
+    for tile in grid.tiles.keys() {
+        let pos = grid.layout.hex_to_world_pos(*tile);
+        upper_right = upper_right.max(pos);
+        lower_left = lower_left.min(pos);
+    }
+    if grid.tiles.is_empty() {
+        upper_right = Vec2::ZERO;
+        lower_left = Vec2::ZERO;
+    }
 
-    let mut lower_left = grid
-        .layout
-        .hex_to_world_pos(Hex { x: -grid.dimension, y: -grid.dimension / 2 });
+    upper_right.y += grid.layout.rect_size().y / 2.0;
     lower_left.y += grid.layout.rect_size().y / 2.0;
 
     c.insert_resource(CameraBoundary { upper_right, lower_left });
@@ -1,5 +1,6 @@
 mod camera_control;
 mod cursor;
+mod fog;
 mod map_control;
 mod map_effects;
 mod mapgen;
@@ -8,6 +9,7 @@ mod tile_states;
 
 pub(crate) use camera_control::*;
 pub(crate) use cursor::*;
+pub(crate) use fog::*;
 pub(self) use map_control::*;
 pub(self) use map_effects::*;
 pub(self) use mapgen::*;
@@ -60,6 +60,8 @@ pub(crate) struct MapSettings
     pub(crate) select_effect_slice: String,
 
     pub(crate) press_color: Color,
+    /// Color applied to unexplored tiles when fog of war is enabled.
+    pub(crate) fog_color: Color,
 
     /// Minimum radius of the cursor buffer region.
     pub(crate) cursor_buffer_min: f32,
@@ -129,6 +131,7 @@ impl Plugin for MapPlugin
             .add_plugins(MapgenPlugin)
             .add_plugins(MapControlPlugin)
             .add_plugins(MapEffectsPlugin)
+            .add_plugins(MapFogPlugin)
             .add_plugins(TileStatesPlugin)
             .add_systems(First, validate_aseprite.run_if(resource_exists::<MapSettings>));
     }
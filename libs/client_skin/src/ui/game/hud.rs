@@ -12,6 +12,7 @@ fn edit_header(h: &mut UiSceneHandle)
         .update(|id: TargetId, mut e: TextEditor, context: Res<ClientContext>| {
             match context.client_type {
                 ClientType::Player => write_text!(e, *id, "player{}", context.client_id),
+                ClientType::Watcher => write_text!(e, *id, "watcher{}", context.client_id),
             };
         });
     h.edit("round_info", |h| {
@@ -42,17 +43,33 @@ fn edit_header(h: &mut UiSceneHandle)
              mut e: TextEditor,
              state: Res<State<ClientState>>,
              round: Res<RoundTimer>,
-             ctx: Res<ClientContext>| {
+             ctx: Res<ClientContext>,
+             orders: Query<&QueuedOrders>| {
                 let paused = match round.is_paused() {
                     true => " -- PAUSED",
                     false => "",
                 };
+                let num_orders = orders
+                    .iter()
+                    .find(|orders| orders.owner() == ctx.client_id)
+                    .map(|orders| orders.orders().len())
+                    .unwrap_or_default();
+                let queued = match num_orders {
+                    0 => String::default(),
+                    _ => format!(" -- {num_orders} queued"),
+                };
                 match state.get() {
                     ClientState::TileSelect => {
                         write_text!(e, *id, "Tile Selection{paused}");
                     }
                     ClientState::Play => {
-                        write_text!(e, *id, "Round {}/{}{paused}", round.round(), ctx.duration_config.num_rounds);
+                        write_text!(
+                            e,
+                            *id,
+                            "Round {}/{}{paused}{queued}",
+                            round.round(),
+                            ctx.duration_config.num_rounds
+                        );
                     }
                     ClientState::End => {
                         write_text!(e, *id, "End");
@@ -88,6 +105,18 @@ fn build_hud(mut c: Commands, mut s: SceneBuilder)
             h.edit("top", edit_header);
             h.get("bottom::settings_button")
                 .on_pressed(|mut c: Commands| c.react().broadcast(ToggleSettings));
+            h.get("bottom::production").update_on(
+                broadcast::<ProductionReceived>(),
+                |id: TargetId, event: BroadcastEvent<ProductionReceived>, mut e: TextEditor| {
+                    let Ok(event) = event.try_read() else { return };
+                    let produced: Vec<String> = event
+                        .produced
+                        .iter()
+                        .map(|(resource_id, amount)| format!("+{amount} {}", resource_id.get()))
+                        .collect();
+                    write_text!(e, *id, "Round {} production: {}", event.round, produced.join(", "));
+                },
+            );
         });
 }
 
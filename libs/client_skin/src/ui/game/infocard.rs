@@ -6,32 +6,105 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn build_overlay(mut c: Commands, mut s: SceneBuilder)
+fn build_infocard(
+    event: Trigger<OnAdd, TileSelected>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+    infocards: Query<Entity, With<Infocard>>,
+)
 {
+    for infocard in infocards.iter() {
+        c.entity(infocard).despawn();
+    }
+
+    let tile_entity = event.target();
     c.ui_root()
-        .spawn_scene(("client.game.tileselect", "overlay"), &mut s, |h| {
-            h.insert(StateScoped(ClientState::TileSelect));
-
-            h.get("text")
-                // TODO: get current number of claimed tiles from query
-                .update_on(
-                    broadcast::<AppUpdateEnd>(),
-                    |id: TargetId, mut e: TextEditor, buildings: Res<BuildingData>| {
-                        write_text!(e, *id, "SELECT TILES: 0/{}", buildings.hq[0].ownable_tiles);
-                    },
-                );
+        .spawn_scene(("client.game.infocard", "infocard_root"), &mut s, |h| {
+            h.insert((StateScoped(ClientAppState::Game), Infocard { tile: tile_entity }));
+
+            h.get("infocard_frame::content::tile").update_on(
+                broadcast::<AppUpdateEnd>(),
+                move |id: TargetId, mut e: TextEditor, tiles: Query<&TileId>| {
+                    let Ok(tile_id) = tiles.get(tile_entity) else { return OK };
+                    write_text!(e, *id, "{}", tile_id.get());
+                    OK
+                },
+            );
+
+            h.get("infocard_frame::content::building").update_on(
+                broadcast::<AppUpdateEnd>(),
+                move |id: TargetId,
+                      mut e: TextEditor,
+                      tiles: Query<Option<&AttachedMeta>, With<MapTile>>,
+                      buildings: Query<&BuildingId>| {
+                    let Ok(attached) = tiles.get(tile_entity) else { return OK };
+                    let building = attached.and_then(|attached| buildings.get(**attached).ok());
+                    match building {
+                        Some(building_id) => write_text!(e, *id, "Building: {}", building_id.get()),
+                        None => write_text!(e, *id, "Building: none"),
+                    };
+                    OK
+                },
+            );
+
+            h.get("infocard_frame::content::modifier").update_on(
+                broadcast::<AppUpdateEnd>(),
+                move |id: TargetId,
+                      mut e: TextEditor,
+                      grid: Res<HexGrid>,
+                      building_data: Res<BuildingData>,
+                      tiles: Query<(&MapTile, Option<&AttachedMeta>)>,
+                      buildings: Query<&BuildingId>,
+                      water: Query<(), With<WaterTile>>| {
+                    let Ok((tile, attached)) = tiles.get(tile_entity) else { return OK };
+                    let spec = attached
+                        .and_then(|attached| buildings.get(**attached).ok())
+                        .and_then(|building_id| building_data.get(building_id))
+                        .filter(|spec| matches!(spec.building_type, BuildingTypeSpec::Production { .. }));
+                    let Some(spec) = spec else {
+                        write_text!(e, *id, "");
+                        return OK;
+                    };
+                    let water_neighbors = count_water_neighbors(**tile, &grid, &water);
+                    let percent = production_modifier_percent(spec, water_neighbors);
+                    write_text!(e, *id, "Production modifier: {:+}%", percent);
+                    OK
+                },
+            );
         });
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn remove_infocard(event: Trigger<OnRemove, TileSelected>, mut c: Commands, infocards: Query<(Entity, &Infocard)>)
+{
+    for (entity, infocard) in infocards.iter() {
+        if infocard.tile != event.target() {
+            continue;
+        }
+        c.entity(entity).despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for infocards, which show information about the currently-selected tile.
+#[derive(Component)]
+struct Infocard
+{
+    tile: Entity,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(super) struct GameUiInfocardPlugin;
 
 impl Plugin for GameUiInfocardPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_systems(OnEnter(ClientState::TileSelect), build_overlay);
+        app.add_observer(build_infocard)
+            .add_observer(remove_infocard);
     }
 }
 
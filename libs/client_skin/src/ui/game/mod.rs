@@ -1,9 +1,13 @@
 mod hud;
+mod infocard;
 mod plugin;
 mod settings;
 mod tileselect;
+mod toast;
 
 pub(self) use hud::*;
+pub(self) use infocard::*;
 pub(crate) use plugin::*;
 pub(crate) use settings::*;
 pub(self) use tileselect::*;
+pub(self) use toast::*;
@@ -11,8 +11,10 @@ impl Plugin for GameUiPlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(GameUiHudPlugin)
+            .add_plugins(GameUiInfocardPlugin)
             .add_plugins(GameUiSettingsPlugin)
-            .add_plugins(GameUiTileSelectPlugin);
+            .add_plugins(GameUiTileSelectPlugin)
+            .add_plugins(GameUiToastPlugin);
     }
 }
 
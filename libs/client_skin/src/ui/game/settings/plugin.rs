@@ -35,7 +35,12 @@ fn add_menu_button(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn build_settings(mut c: Commands, mut s: SceneBuilder, localgame: Res<LocalGameManager>)
+fn build_settings(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    localgame: Res<LocalGameManager>,
+    context: Res<ClientContext>,
+)
 {
     c.ui_root()
         .spawn_scene(("client.game.settings", "settings_popup"), &mut s, |h| {
@@ -69,6 +74,7 @@ fn build_settings(mut c: Commands, mut s: SceneBuilder, localgame: Res<LocalGame
             // TODO: restore defaults button to right of title
 
             let content_id = h.get_entity("window::main::content")?;
+            let is_player = context.client_type == ClientType::Player;
 
             h.edit("window::main::sidebar", |h| {
                 let game_section_id = add_menu_button(
@@ -99,7 +105,7 @@ fn build_settings(mut c: Commands, mut s: SceneBuilder, localgame: Res<LocalGame
                     ("client.game.settings", "audio_section"),
                     build_settings_audio_section,
                 );
-                if localgame.is_running() || cfg!(feature = "dev") {
+                if is_player && (localgame.is_running() || cfg!(feature = "dev")) {
                     add_menu_button(
                         h,
                         content_id,
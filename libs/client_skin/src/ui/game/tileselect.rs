@@ -1,27 +1,67 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
+use bevy_girk_utils::Sender;
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Toggles tile selection when a tile is clicked during tile select.
+///
+/// Watchers can't select tiles.
+fn handle_tile_clicked(
+    event: Trigger<OnInsert, TileSelected>,
+    state: Option<Res<State<ClientState>>>,
+    context: Res<ClientContext>,
+    sender: Res<Sender<PlayerInput>>,
+    tiles: Query<(&MapTile, Option<&AttachedMeta>), With<OwnableTile>>,
+    owners: Query<&TileOwner>,
+)
+{
+    if state.map(|s| *s.get()) != Some(ClientState::TileSelect) {
+        return;
+    }
+    if context.client_type != ClientType::Player {
+        return;
+    }
+    let Ok((tile, attached)) = tiles.get(event.target()) else { return };
+
+    let is_selected = attached
+        .and_then(|attached| owners.get(**attached).ok())
+        .map(|owner| owner.0 == context.client_id)
+        .unwrap_or_default();
+    let input = match is_selected {
+        true => PlayerInput::DeselectTile(**tile),
+        false => PlayerInput::SelectTile(**tile),
+    };
+    let _ = sender.send(input);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn build_overlay(mut c: Commands, mut s: SceneBuilder)
 {
     c.ui_root()
         .spawn_scene(("client.game.tileselect", "overlay"), &mut s, |h| {
             h.insert(StateScoped(ClientState::TileSelect));
 
-            h.get("text")
-                // TODO: get current number of claimed tiles from query
-                .update_on(
-                    broadcast::<AppUpdateEnd>(),
-                    |id: TargetId, mut e: TextEditor, buildings: Res<BuildingData>| {
-                        let total_tiles = buildings.get_tileselect_tiles().result()?;
-                        write_text!(e, *id, "SELECT TILES: 0/{}", total_tiles);
-                        OK
-                    },
-                );
+            h.get("text").update_on(
+                broadcast::<AppUpdateEnd>(),
+                |id: TargetId,
+                 mut e: TextEditor,
+                 buildings: Res<BuildingData>,
+                 context: Res<ClientContext>,
+                 selected: Query<&SelectableTile, With<TileOwner>>| {
+                    let total_tiles = buildings.get_tileselect_tiles().result()?;
+                    let selected = selected
+                        .iter()
+                        .filter(|selected| selected.client == context.client_id)
+                        .count();
+                    write_text!(e, *id, "SELECT TILES: {}/{}", selected, total_tiles);
+                    OK
+                },
+            );
         });
 }
 
@@ -33,7 +73,10 @@ impl Plugin for GameUiTileSelectPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_systems(OnEnter(ClientState::TileSelect), build_overlay);
+        app.add_observer(handle_tile_clicked).add_systems(
+            OnEnter(ClientState::TileSelect),
+            build_overlay.run_if(|context: Res<ClientContext>| context.client_type == ClientType::Player),
+        );
     }
 }
 
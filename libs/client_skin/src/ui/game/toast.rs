@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const TOAST_DURATION: Duration = Duration::from_millis(3000);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn rejection_message(reason: &RejectionReason) -> String
+{
+    match reason {
+        RejectionReason::WrongPhase => "Can't do that right now".into(),
+        RejectionReason::RateLimited => "Too many requests, slow down".into(),
+        RejectionReason::InvalidTile => "That tile can't be owned".into(),
+        RejectionReason::TileNotOwned => "You don't own that tile".into(),
+        RejectionReason::TileAlreadyOwned => "That tile is already owned".into(),
+        RejectionReason::TileLimitReached => "You can't own any more tiles".into(),
+        RejectionReason::AlreadyClaimed => "You already claimed that tile".into(),
+        RejectionReason::NotClaimed => "You haven't claimed that tile".into(),
+        RejectionReason::InvalidBuilding => "That building can't be built there".into(),
+        RejectionReason::TileHasBuilding => "That tile already has a building".into(),
+        RejectionReason::NoBuilding => "That tile doesn't have a building".into(),
+        RejectionReason::Indestructible => "That building can't be demolished".into(),
+        RejectionReason::ProximityRequirementNotMet => "That building needs different surroundings".into(),
+        RejectionReason::InsufficientResources { shortfall } => {
+            let mut missing: Vec<(&str, u64)> = shortfall
+                .iter()
+                .map(|(resource_id, amount)| (resource_id.get(), *amount))
+                .collect();
+            missing.sort_unstable();
+            let missing: Vec<String> = missing
+                .into_iter()
+                .map(|(resource, amount)| format!("{amount} {resource}"))
+                .collect();
+            format!("Not enough resources, need {} more", missing.join(", "))
+        }
+        RejectionReason::InsufficientGold { shortfall } => format!("Not enough gold, need {shortfall} more"),
+        RejectionReason::NoQueuedOrder => "You don't have an order on that tile".into(),
+        RejectionReason::TooManyOrders => "You can't queue any more orders this round".into(),
+        RejectionReason::Invalid | RejectionReason::None => "Request failed".into(),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Summarizes the tile claims of one player.
+///
+/// Returns `None` if the player had no claims resolved.
+fn claim_results_message(client_id: ClientId, round: u32, results: &[TileClaimResult]) -> Option<String>
+{
+    let mut won = 0;
+    let mut gold_spent: u64 = 0;
+    let mut unaffordable = 0;
+    let mut contested = 0;
+    for result in results {
+        match result {
+            TileClaimResult::Won { owner, cost, .. } if *owner == client_id => {
+                won += 1;
+                gold_spent += *cost;
+            }
+            TileClaimResult::Unaffordable { claimant, .. } if *claimant == client_id => unaffordable += 1,
+            TileClaimResult::Contested { claimants, .. } if claimants.contains(&client_id) => contested += 1,
+            _ => (),
+        }
+    }
+
+    let mut parts = Vec::default();
+    if won > 0 {
+        parts.push(format!("won {won} tile(s) for {gold_spent} gold"));
+    }
+    if unaffordable > 0 {
+        parts.push(format!("couldn't afford {unaffordable} tile(s)"));
+    }
+    if contested > 0 {
+        parts.push(format!("{contested} tile(s) contested"));
+    }
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(format!("Round {round} claims: {}", parts.join(", ")))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a toast, replacing any existing toast.
+fn spawn_toast(c: &mut Commands, s: &mut SceneBuilder, toasts: &Query<Entity, With<Toast>>, message: String)
+{
+    // Only show the most recent toast.
+    for toast in toasts.iter() {
+        c.entity(toast).despawn();
+    }
+
+    c.ui_root()
+        .spawn_scene(("client.game.toast", "toast"), s, |h| {
+            h.insert((
+                StateScoped(ClientAppState::Game),
+                Toast { timer: Timer::new(TOAST_DURATION, TimerMode::Once) },
+            ));
+            h.get("frame::text").update_text(message);
+        });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn show_rejection_toast(
+    event: BroadcastEvent<RequestRejected>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+    toasts: Query<Entity, With<Toast>>,
+)
+{
+    let Ok(event) = event.try_read() else { return };
+    spawn_toast(&mut c, &mut s, &toasts, rejection_message(&event.reason));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn show_claim_results_toast(
+    event: BroadcastEvent<TileClaimsResolved>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+    ctx: Res<ClientContext>,
+    toasts: Query<Entity, With<Toast>>,
+)
+{
+    let Ok(event) = event.try_read() else { return };
+    let Some(message) = claim_results_message(ctx.client_id, event.round, &event.results) else { return };
+    spawn_toast(&mut c, &mut s, &toasts, message);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn update_toasts(mut c: Commands, time: Res<Time>, mut toasts: Query<(Entity, &mut Toast)>)
+{
+    for (entity, mut toast) in toasts.iter_mut() {
+        if !toast.timer.tick(time.delta()).finished() {
+            continue;
+        }
+        c.entity(entity).despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for toast popups, which are despawned when their timer runs out.
+#[derive(Component)]
+struct Toast
+{
+    timer: Timer,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct GameUiToastPlugin;
+
+impl Plugin for GameUiToastPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_reactor(broadcast::<RequestRejected>(), show_rejection_toast)
+            .add_reactor(broadcast::<TileClaimsResolved>(), show_claim_results_toast)
+            .add_systems(Update, update_toasts.run_if(in_state(ClientAppState::Game)));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
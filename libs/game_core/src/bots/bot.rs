@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use bevy_girk_utils::Rand64;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Difficulty level of a bot player.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum BotDifficulty
+{
+    /// Makes random choices and takes few actions each round.
+    Easy,
+    #[default]
+    Normal,
+    /// Takes more actions each round, expands compactly, and upgrades buildings.
+    Hard,
+}
+
+impl BotDifficulty
+{
+    /// Max number of tile claims the bot will make each round.
+    pub fn claims_per_round(&self) -> usize
+    {
+        match self {
+            Self::Easy => 1,
+            Self::Normal => 2,
+            Self::Hard => 3,
+        }
+    }
+
+    /// Max number of buildings the bot will construct or upgrade each round.
+    pub fn builds_per_round(&self) -> usize
+    {
+        match self {
+            Self::Easy => 1,
+            Self::Normal => 2,
+            Self::Hard => 4,
+        }
+    }
+
+    /// Whether the bot will upgrade existing buildings.
+    pub fn upgrades_buildings(&self) -> bool
+    {
+        matches!(self, Self::Hard)
+    }
+
+    /// Whether the bot prefers tiles next to tiles it already owns.
+    pub fn expands_compactly(&self) -> bool
+    {
+        matches!(self, Self::Hard)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for player entities controlled by the server.
+///
+/// Bots issue the same [`PlayerInput`]s as human players. They are not network clients, so they never receive
+/// game messages.
+#[derive(Component)]
+pub struct Bot
+{
+    difficulty: BotDifficulty,
+    rand: Rand64,
+    /// The last game state and round the bot took a turn in.
+    last_turn: Option<(GameState, u32)>,
+}
+
+impl Bot
+{
+    pub(crate) fn new(difficulty: BotDifficulty, seed: u64, player_id: PlayerId) -> Self
+    {
+        Self {
+            difficulty,
+            rand: make_player_rand("BOT PRNG", seed, player_id),
+            last_turn: None,
+        }
+    }
+
+    pub fn difficulty(&self) -> BotDifficulty
+    {
+        self.difficulty
+    }
+
+    /// Marks the turn as taken. Returns false if the bot already took a turn in this state and round.
+    pub(crate) fn try_take_turn(&mut self, state: GameState, round: u32) -> bool
+    {
+        if self.last_turn == Some((state, round)) {
+            return false;
+        }
+        self.last_turn = Some((state, round));
+        true
+    }
+
+    /// Scores a choice with the given value. Choices with higher scores are preferred.
+    ///
+    /// Values are ignored on easy difficulty, and otherwise ties are broken randomly.
+    pub(crate) fn score(&mut self, value: u32) -> u64
+    {
+        let roll = self.rand.next() % 1000;
+        match self.difficulty {
+            BotDifficulty::Easy => roll,
+            BotDifficulty::Normal | BotDifficulty::Hard => (value as u64) * 1000 + roll,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
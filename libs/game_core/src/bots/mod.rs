@@ -0,0 +1,5 @@
+mod bot;
+mod turns;
+
+pub use bot::*;
+pub(crate) use turns::*;
@@ -0,0 +1,288 @@
+use std::cmp::Reverse;
+
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_game_fw::GameFwState;
+use hexx::Hex;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How valuable a tile is to own.
+fn tile_value(spec: Option<&TileSpec>) -> u32
+{
+    spec.map(|spec| spec.builds_into.len() as u32)
+        .unwrap_or_default()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How much a bot wants to construct a building.
+fn building_value(spec: &BuildingSpec) -> u32
+{
+    match &spec.building_type {
+        BuildingTypeSpec::Hq { .. } => 0,
+        BuildingTypeSpec::Transportation { .. } => 1,
+        BuildingTypeSpec::Service { .. } => 2,
+        BuildingTypeSpec::Production { .. } => 3,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chooses tiles for a bot to select during tile select.
+fn plan_tile_select(
+    In((player_entity, client_id)): In<(Entity, ClientId)>,
+    mut bots: Query<&mut Bot>,
+    buildings: Res<BuildingData>,
+    tile_data: Res<TileData>,
+    tiles: Query<&TileId, With<MapTile>>,
+    selectable: Query<(&TileMeta, &ParentTile, &SelectableTile), Without<TileOwner>>,
+    selected: Query<&SelectableTile, With<TileOwner>>,
+) -> Vec<PlayerInput>
+{
+    let Ok(mut bot) = bots.get_mut(player_entity) else { return Vec::default() };
+    let allowed = buildings.get_tileselect_tiles().unwrap_or_default() as usize;
+    let num_selected = selected
+        .iter()
+        .filter(|selected| selected.client == client_id)
+        .count();
+
+    // Sort before scoring so random rolls are deterministic.
+    let mut candidates: Vec<(Hex, u32)> = selectable
+        .iter()
+        .filter(|(_, _, selectable)| selectable.client == client_id)
+        .map(|(meta, parent, _)| {
+            (
+                meta.tile,
+                tile_value(tiles.get(**parent).ok().and_then(|id| tile_data.get(id))),
+            )
+        })
+        .collect();
+    candidates.sort_unstable_by_key(|(tile, _)| (tile.x, tile.y));
+    let mut candidates: Vec<(u64, Hex)> = candidates
+        .into_iter()
+        .map(|(tile, value)| (bot.score(value), tile))
+        .collect();
+    candidates.sort_unstable_by_key(|(score, tile)| (Reverse(*score), tile.x, tile.y));
+
+    candidates
+        .into_iter()
+        .take(allowed.saturating_sub(num_selected))
+        .map(|(_, tile)| PlayerInput::SelectTile(tile))
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chooses unowned tiles next to a bot's territory to claim.
+///
+/// Claim costs are spent from `inventory`, which is returned so builds are planned with the remaining budget.
+fn plan_claims(
+    In((player_entity, client_id, mut inventory)): In<(Entity, ClientId, PlayerInventory)>,
+    mut bots: Query<&mut Bot>,
+    settings: Res<TileClaimSettings>,
+    grid: Res<HexGrid>,
+    tile_data: Res<TileData>,
+    tiles: Query<(&TileId, Option<&AttachedMeta>), (With<MapTile>, With<OwnableTile>)>,
+    metas: Query<(&TileMeta, Option<&TileOwner>, Option<&TileClaims>)>,
+) -> (Vec<PlayerInput>, PlayerInventory)
+{
+    let Ok(mut bot) = bots.get_mut(player_entity) else { return (Vec::default(), inventory) };
+    let difficulty = bot.difficulty();
+
+    let owned: HashSet<Hex> = metas
+        .iter()
+        .filter(|(_, owner, _)| owner.is_some_and(|owner| owner.0 == client_id))
+        .map(|(meta, ..)| meta.tile)
+        .collect();
+    let mut neighbors: Vec<Hex> = owned
+        .iter()
+        .flat_map(|tile| tile.all_neighbors())
+        .filter(|tile| !owned.contains(tile))
+        .collect::<HashSet<Hex>>()
+        .into_iter()
+        .collect();
+    neighbors.sort_unstable_by_key(|tile| (tile.x, tile.y));
+
+    let mut candidates = Vec::with_capacity(neighbors.len());
+    for tile in neighbors {
+        let Some((tile_id, attached)) = grid
+            .tiles
+            .get(&tile)
+            .and_then(|tile_entity| tiles.get(*tile_entity).ok())
+        else {
+            continue;
+        };
+        let mut age = 0;
+        if let Some((_, owner, claims)) = attached.and_then(|attached| metas.get(**attached).ok()) {
+            if owner.is_some() || claims.is_some_and(|claims| claims.claimants().contains(&client_id)) {
+                continue;
+            }
+            age = claims.map(|claims| claims.age()).unwrap_or_default();
+        }
+
+        let mut value = tile_value(tile_data.get(tile_id));
+        if difficulty.expands_compactly() {
+            value += tile
+                .all_neighbors()
+                .iter()
+                .filter(|n| owned.contains(*n))
+                .count() as u32;
+        }
+        candidates.push((bot.score(value), tile, settings.claim_cost(age)));
+    }
+    candidates.sort_unstable_by_key(|(score, tile, _)| (Reverse(*score), tile.x, tile.y));
+
+    let mut inputs = Vec::default();
+    for (_, tile, cost) in candidates {
+        if inputs.len() >= difficulty.claims_per_round() {
+            break;
+        }
+        if inventory.try_spend_gold(cost).is_err() {
+            continue;
+        }
+        inputs.push(PlayerInput::ClaimTile(tile));
+    }
+
+    (inputs, inventory)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chooses buildings for a bot to construct or upgrade on its tiles.
+///
+/// Build costs are spent from `inventory`, which should have the costs of the bot's other plans for the turn
+/// already removed.
+fn plan_builds(
+    In((player_entity, mut inventory)): In<(Entity, PlayerInventory)>,
+    mut bots: Query<&mut Bot>,
+    tile_data: Res<TileData>,
+    buildings: Res<BuildingData>,
+    tiles: Query<&TileId, With<MapTile>>,
+    metas: Query<(&TileMeta, &ParentTile, &TileOwner, Option<&BuildingId>)>,
+) -> Vec<PlayerInput>
+{
+    let Ok(mut bot) = bots.get_mut(player_entity) else { return Vec::default() };
+    let difficulty = bot.difficulty();
+    let client_id = inventory.owner();
+
+    let mut owned: Vec<_> = metas
+        .iter()
+        .filter(|(_, _, owner, _)| owner.0 == client_id)
+        .collect();
+    owned.sort_unstable_by_key(|(meta, ..)| (meta.tile.x, meta.tile.y));
+
+    let mut options = Vec::default();
+    for (meta, parent, _, existing) in owned {
+        let (builds_into, upgrade) = match existing {
+            None => {
+                let Some(spec) = tiles.get(**parent).ok().and_then(|id| tile_data.get(id)) else { continue };
+                (&spec.builds_into, false)
+            }
+            Some(existing) if difficulty.upgrades_buildings() => {
+                let Some(spec) = buildings.get(existing) else { continue };
+                (&spec.builds_into, true)
+            }
+            Some(_) => continue,
+        };
+
+        for building_id in builds_into.iter() {
+            let Some(spec) = buildings.get(building_id) else { continue };
+            let BuildCost::Build { resources, .. } = &spec.build_cost else { continue };
+            let input = match upgrade {
+                false => PlayerInput::Build { tile: meta.tile, building: building_id.clone() },
+                true => PlayerInput::Upgrade { tile: meta.tile, building: building_id.clone() },
+            };
+            options.push((bot.score(building_value(spec)), meta.tile, input, resources));
+        }
+    }
+    options.sort_by_key(|(score, tile, ..)| (Reverse(*score), tile.x, tile.y));
+
+    // Track spending so the bot doesn't plan more than it can afford.
+    let mut used = HashSet::<Hex>::default();
+    let mut inputs = Vec::default();
+    for (_, tile, input, cost) in options {
+        if inputs.len() >= difficulty.builds_per_round() {
+            break;
+        }
+        if used.contains(&tile) || inventory.try_spend(cost).is_err() {
+            continue;
+        }
+        used.insert(tile);
+        inputs.push(input);
+    }
+
+    inputs
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Lets each bot take one turn at the start of tile select and at the start of each round.
+///
+/// Bot inputs are handled by [`handle_player_input`] just like inputs from human players.
+fn run_bots(world: &mut World)
+{
+    let state = world.syscall((), get_game_state);
+    let round = **world.resource::<GameRound>();
+    match state {
+        GameState::TileSelect => (),
+        GameState::Play if round > 0 => (),
+        _ => return,
+    }
+
+    let mut bots: Vec<(Entity, ClientId)> = world
+        .query::<(Entity, &PlayerId, &mut Bot)>()
+        .iter_mut(world)
+        .filter_map(|(entity, id, mut bot)| bot.try_take_turn(state, round).then_some((entity, id.id)))
+        .collect();
+    bots.sort_unstable_by_key(|(_, client_id)| *client_id);
+
+    for (player_entity, client_id) in bots {
+        let inputs = match state {
+            GameState::TileSelect => world.syscall((player_entity, client_id), plan_tile_select),
+            _ => {
+                // Plan claims and builds from one copy of the inventory so the bot doesn't overspend.
+                let Some(inventory) = world
+                    .query::<&PlayerInventory>()
+                    .iter(world)
+                    .find(|inventory| inventory.owner() == client_id)
+                    .cloned()
+                else {
+                    tracing::error!("bot {client_id} is missing a player inventory");
+                    continue;
+                };
+                let (mut inputs, inventory) = world.syscall((player_entity, client_id, inventory), plan_claims);
+                inputs.extend(world.syscall((player_entity, inventory), plan_builds));
+                inputs
+            }
+        };
+
+        for input in inputs {
+            tracing::debug!(?input, "bot {client_id} submitting input");
+            world.syscall((player_entity, client_id, input), handle_player_input);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct BotPlugin;
+
+impl Plugin for BotPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            Update,
+            run_bots
+                .run_if(in_state(GameFwState::Game))
+                .after(GameLogicSet::Admin)
+                .before(GameLogicSet::Update),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
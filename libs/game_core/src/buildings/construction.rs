@@ -0,0 +1,230 @@
+use bevy::prelude::*;
+use hexx::Hex;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds the meta entity of a tile owned by a player.
+///
+/// Returns the tile's type, the meta entity, and the building currently on the tile.
+fn get_owned_tile<'a>(
+    client_id: ClientId,
+    tile: Hex,
+    grid: &HexGrid,
+    tiles: &'a Query<(&TileId, Option<&AttachedMeta>), With<MapTile>>,
+    metas: &'a Query<(&TileOwner, Option<&BuildingId>), With<TileMeta>>,
+) -> Result<(&'a TileId, Entity, Option<&'a BuildingId>), RejectionReason>
+{
+    let Some((tile_id, attached)) = grid
+        .tiles
+        .get(&tile)
+        .and_then(|tile_entity| tiles.get(*tile_entity).ok())
+    else {
+        tracing::debug!(?tile, "client {client_id} tried to modify a tile that doesn't exist");
+        return Err(RejectionReason::InvalidTile);
+    };
+    let Some((meta, (owner, building))) = attached
+        .map(|attached| **attached)
+        .and_then(|meta| metas.get(meta).ok().map(|info| (meta, info)))
+    else {
+        tracing::debug!(?tile, "client {client_id} tried to modify an unowned tile");
+        return Err(RejectionReason::TileNotOwned);
+    };
+    if owner.0 != client_id {
+        tracing::debug!(?tile, "client {client_id} tried to modify a tile owned by {}", owner.0);
+        return Err(RejectionReason::TileNotOwned);
+    }
+
+    Ok((tile_id, meta, building))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks that a tile's surroundings meet the proximity requirements of a building.
+///
+/// Unknown and system-only buildings have no requirements. They are rejected when paying the build cost.
+fn check_proximity_requirements(
+    client_id: ClientId,
+    tile: Hex,
+    spec: Option<&BuildingSpec>,
+    grid: &HexGrid,
+    tiles: &Query<(&TileId, Option<&AttachedMeta>), With<MapTile>>,
+    metas: &Query<(&TileOwner, Option<&BuildingId>), With<TileMeta>>,
+    water: &Query<(), With<WaterTile>>,
+) -> Result<(), RejectionReason>
+{
+    let Some(BuildCost::Build { proximity, .. }) = spec.map(|spec| &spec.build_cost) else { return Ok(()) };
+
+    for requirement in proximity.iter() {
+        let is_met = tile
+            .range(requirement.distance())
+            .filter(|nearby| *nearby != tile)
+            .filter_map(|nearby| grid.tiles.get(&nearby).copied())
+            .any(|nearby| match requirement {
+                ProximityRequirement::Water { .. } => water.contains(nearby),
+                ProximityRequirement::Tile { tile: required, owned, .. } => {
+                    let Ok((tile_id, attached)) = tiles.get(nearby) else { return false };
+                    if tile_id != required {
+                        return false;
+                    }
+                    !*owned
+                        || attached
+                            .and_then(|attached| metas.get(**attached).ok())
+                            .is_some_and(|(owner, _)| owner.0 == client_id)
+                }
+            });
+
+        if !is_met {
+            tracing::debug!(?tile, ?requirement, "client {client_id} tried to construct a building without meeting \
+                its proximity requirements");
+            return Err(RejectionReason::ProximityRequirementNotMet);
+        }
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pays the cost of constructing a building.
+fn pay_build_cost(
+    client_id: ClientId,
+    building_id: &BuildingId,
+    buildings: &BuildingData,
+    inventories: &mut Query<&mut PlayerInventory>,
+) -> Result<(), RejectionReason>
+{
+    let Some(spec) = buildings.get(building_id) else {
+        tracing::debug!("client {client_id} tried to construct unknown building {building_id:?}");
+        return Err(RejectionReason::InvalidBuilding);
+    };
+    let BuildCost::Build { resources, .. } = &spec.build_cost else {
+        tracing::debug!("client {client_id} tried to construct system-only building {building_id:?}");
+        return Err(RejectionReason::InvalidBuilding);
+    };
+    let Some(mut inventory) = get_inventory_mut(inventories, client_id) else {
+        tracing::error!("client {client_id} is missing a player inventory");
+        return Err(RejectionReason::Invalid);
+    };
+    if let Err(shortfall) = inventory.try_spend(resources) {
+        tracing::debug!(?shortfall, "client {client_id} can't afford to construct {building_id:?}");
+        return Err(RejectionReason::InsufficientResources { shortfall });
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Constructs a building on an empty owned tile.
+pub(crate) fn handle_build(
+    In((_player_entity, client_id, (tile, building_id))): In<(Entity, ClientId, (Hex, BuildingId))>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    tile_data: Res<TileData>,
+    buildings: Res<BuildingData>,
+    tiles: Query<(&TileId, Option<&AttachedMeta>), With<MapTile>>,
+    metas: Query<(&TileOwner, Option<&BuildingId>), With<TileMeta>>,
+    water: Query<(), With<WaterTile>>,
+    mut inventories: Query<&mut PlayerInventory>,
+) -> Result<(), RejectionReason>
+{
+    let (tile_id, meta, existing) = get_owned_tile(client_id, tile, &grid, &tiles, &metas)?;
+    if let Some(existing) = existing {
+        tracing::debug!(?tile, "client {client_id} tried to construct {building_id:?} on top of {existing:?}");
+        return Err(RejectionReason::TileHasBuilding);
+    }
+    let Some(tile_spec) = tile_data.get(tile_id) else {
+        tracing::error!(?tile, "tile {tile_id:?} is missing from tile data");
+        return Err(RejectionReason::Invalid);
+    };
+    if !tile_spec.builds_into.contains(&building_id) {
+        tracing::debug!(?tile, "client {client_id} tried to construct {building_id:?} on {tile_id:?}");
+        return Err(RejectionReason::InvalidBuilding);
+    }
+
+    let spec = buildings.get(&building_id);
+    check_proximity_requirements(client_id, tile, spec, &grid, &tiles, &metas, &water)?;
+    pay_build_cost(client_id, &building_id, &buildings, &mut inventories)?;
+    c.entity(meta).insert(building_id);
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Upgrades the building on an owned tile.
+pub(crate) fn handle_upgrade(
+    In((_player_entity, client_id, (tile, building_id))): In<(Entity, ClientId, (Hex, BuildingId))>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    buildings: Res<BuildingData>,
+    tiles: Query<(&TileId, Option<&AttachedMeta>), With<MapTile>>,
+    metas: Query<(&TileOwner, Option<&BuildingId>), With<TileMeta>>,
+    water: Query<(), With<WaterTile>>,
+    mut inventories: Query<&mut PlayerInventory>,
+) -> Result<(), RejectionReason>
+{
+    let (_, meta, existing) = get_owned_tile(client_id, tile, &grid, &tiles, &metas)?;
+    let Some(existing) = existing else {
+        tracing::debug!(?tile, "client {client_id} tried to upgrade a tile without a building");
+        return Err(RejectionReason::NoBuilding);
+    };
+    let Some(existing_spec) = buildings.get(existing) else {
+        tracing::error!(?tile, "building {existing:?} is missing from building data");
+        return Err(RejectionReason::Invalid);
+    };
+    if !existing_spec.builds_into.contains(&building_id) {
+        tracing::debug!(?tile, "client {client_id} tried to upgrade {existing:?} into {building_id:?}");
+        return Err(RejectionReason::InvalidBuilding);
+    }
+
+    let spec = buildings.get(&building_id);
+    check_proximity_requirements(client_id, tile, spec, &grid, &tiles, &metas, &water)?;
+    pay_build_cost(client_id, &building_id, &buildings, &mut inventories)?;
+    c.entity(meta).insert(building_id);
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Demolishes the building on an owned tile.
+pub(crate) fn handle_demolish(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    buildings: Res<BuildingData>,
+    tiles: Query<(&TileId, Option<&AttachedMeta>), With<MapTile>>,
+    metas: Query<(&TileOwner, Option<&BuildingId>), With<TileMeta>>,
+    mut inventories: Query<&mut PlayerInventory>,
+) -> Result<(), RejectionReason>
+{
+    let (_, meta, existing) = get_owned_tile(client_id, tile, &grid, &tiles, &metas)?;
+    let Some(existing) = existing else {
+        tracing::debug!(?tile, "client {client_id} tried to demolish a tile without a building");
+        return Err(RejectionReason::NoBuilding);
+    };
+    let Some(cost) = buildings
+        .get(existing)
+        .and_then(|spec| spec.destruction_cost.as_ref())
+    else {
+        tracing::debug!(?tile, "client {client_id} tried to demolish indestructible building {existing:?}");
+        return Err(RejectionReason::Indestructible);
+    };
+    let Some(mut inventory) = get_inventory_mut(&mut inventories, client_id) else {
+        tracing::error!("client {client_id} is missing a player inventory");
+        return Err(RejectionReason::Invalid);
+    };
+    if let Err(shortfall) = inventory.try_spend(cost) {
+        tracing::debug!(?shortfall, "client {client_id} can't afford to demolish {existing:?}");
+        return Err(RejectionReason::InsufficientResources { shortfall });
+    }
+
+    c.entity(meta).remove::<BuildingId>();
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -2,7 +2,7 @@ use std::borrow::{Borrow, Cow};
 
 use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utils::RootConfigs;
 
 use crate::*;
@@ -10,7 +10,9 @@ use crate::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component with the canonical ID of a type of building.
-#[derive(Component, Debug, Deserialize, Clone, Eq, PartialEq, Hash, Reflect)]
+///
+/// Inserted on [`TileMeta`] entities for tiles that have a building.
+#[derive(Component, Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Hash, Deserialize)]
 #[component(immutable)]
 pub struct BuildingId(Cow<'static, str>);
@@ -39,46 +41,61 @@ impl Borrow<str> for BuildingId
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Cost to construct a building (can be from bare tile or as upgrade from another building).
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum BuildCost
 {
     System,
     Build
     {
         resources: ResourceCost,
-        // TODO: service requirements (optional)
-        // TODO: tile proximity requirements (optional)
+        /// Service units the building needs each round to operate at full capacity.
+        #[serde(default)]
+        services: HashMap<ServiceId, u64>,
+        /// Requirements on the tiles around the building. All must be met to construct it.
+        #[serde(default)]
+        proximity: Vec<ProximityRequirement>,
     },
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum BuildingTypeSpec
 {
     Hq
     {
-        total_tiles_allowed: u16
+        /// Number of tiles each player may select during tile select, not counting the HQ tile.
+        total_tiles_allowed: u16,
     },
     Production
     {
         resource_id: ResourceId, production_per_round: u64
     },
-    // TODO: Service{
-    //     service: ServiceType,  // type of one unit of service
-    //     capacity: u64,
-    //     // For transport calculations.
-    //     capacity_use_weight: u64
-    // }
-    // TODO: Transportation{
-    //     radius: u32,
-    //     disrepair_rate: f32
-    // }
+    Service
+    {
+        /// Type of one unit of service.
+        service: ServiceId,
+        /// Units of service provided each round when the building's own service requirements are met.
+        capacity: u64,
+        /// For transport calculations. Service buildings that aren't connected to their owner's HQ have their
+        /// capacity scaled by [`TransportSettings::unconnected_production_factor`] once for each unit of weight.
+        capacity_use_weight: u64,
+    },
+    Transportation
+    {
+        /// Tiles within this hex distance are covered by the building.
+        radius: u32,
+        /// Condition lost each round the building isn't maintained.
+        disrepair_rate: f32,
+        /// Cost paid at the end of each round to keep the building in full condition.
+        #[serde(default)]
+        maintenance_cost: ResourceCost,
+    },
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BuildingSpec
 {
     pub build_cost: BuildCost,
@@ -86,11 +103,21 @@ pub struct BuildingSpec
     pub building_type: BuildingTypeSpec,
     /// Indestructible if None.
     pub destruction_cost: Option<ResourceCost>,
+    /// Production modifiers that apply based on the building's surroundings.
+    #[serde(default)]
+    pub proximity_modifiers: Vec<ProximityModifier>,
 }
 
 impl BuildingSpec
 {
-    fn validate(&self, id: &BuildingId, buildings: &BuildingData, resources: &ResourceData) -> Result<(), String>
+    fn validate(
+        &self,
+        id: &BuildingId,
+        buildings: &BuildingData,
+        resources: &ResourceData,
+        services: &ServiceData,
+        tiles: &TileData,
+    ) -> Result<(), String>
     {
         let mut building_ids = HashSet::with_capacity(self.builds_into.len());
         for building_id in self.builds_into.iter() {
@@ -102,6 +129,48 @@ impl BuildingSpec
             }
         }
 
+        if !self.proximity_modifiers.is_empty()
+            && !matches!(self.building_type, BuildingTypeSpec::Production { .. })
+        {
+            return Err(format!("{:?} has spec with proximity modifiers but is not production-type", id));
+        }
+        for modifier in self.proximity_modifiers.iter() {
+            modifier.validate(id)?;
+        }
+
+        let mut costs: Vec<&ResourceCost> = self.destruction_cost.iter().collect();
+        if let BuildingTypeSpec::Transportation { maintenance_cost, .. } = &self.building_type {
+            costs.push(maintenance_cost);
+        }
+        if let BuildCost::Build {
+            resources: build_resources,
+            services: required_services,
+            proximity,
+        } = &self.build_cost
+        {
+            costs.push(build_resources);
+
+            for requirement in proximity.iter() {
+                requirement.validate(id, tiles)?;
+            }
+
+            for (service_id, amount) in required_services.iter() {
+                if !services.contains(service_id) {
+                    return Err(format!("{:?} has spec that requires unregistered service {:?}", id, service_id));
+                }
+                if *amount == 0 {
+                    return Err(format!("{:?} has spec that requires 0 units of service {:?}", id, service_id));
+                }
+            }
+        }
+        for resource_id in costs.iter().flat_map(|cost| cost.keys()) {
+            if !resources.contains(resource_id) {
+                return Err(
+                    format!("{:?} has spec with a cost that includes unregistered resource {:?}", id, resource_id),
+                );
+            }
+        }
+
         match &self.building_type {
             BuildingTypeSpec::Hq { .. } => (),
             BuildingTypeSpec::Production { resource_id, production_per_round } => {
@@ -114,12 +183,31 @@ impl BuildingSpec
                 if *production_per_round == 0 {
                     return Err(format!("{:?} has spec for production-type but production per round is 0", id));
                 }
-            } /* BuildingTypeSpec::Service { .. } => {
-               *     // service unit type is known
-               * },
-               * BuildingTypeSpec::Transportation { .. } => {
-               *     // radius not zero
-               * }, */
+            }
+            BuildingTypeSpec::Service { service, capacity, capacity_use_weight } => {
+                if !services.contains(service) {
+                    return Err(
+                        format!("{:?} has spec for service-type but service {:?} is unregistered", id, service),
+                    );
+                }
+                if *capacity == 0 {
+                    return Err(format!("{:?} has spec for service-type but capacity is 0", id));
+                }
+                if *capacity_use_weight == 0 {
+                    return Err(format!("{:?} has spec for service-type but capacity use weight is 0", id));
+                }
+            }
+            BuildingTypeSpec::Transportation { radius, disrepair_rate, .. } => {
+                if *radius == 0 {
+                    return Err(format!("{:?} has spec for transportation-type but radius is 0", id));
+                }
+                if !(0.0..=1.0).contains(disrepair_rate) {
+                    return Err(format!(
+                        "{:?} has spec for transportation-type but disrepair rate {} is not between 0 and 1",
+                        id, disrepair_rate
+                    ));
+                }
+            }
         }
 
         Ok(())
@@ -128,7 +216,7 @@ impl BuildingSpec
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Resource, Debug, Deserialize, Clone, Deref)]
+#[derive(Resource, Debug, Serialize, Deserialize, Clone, Deref)]
 pub struct BuildingData(HashMap<BuildingId, BuildingSpec>);
 
 impl BuildingData
@@ -138,15 +226,75 @@ impl BuildingData
         configs.get_type_from_file::<Self>("building_data")
     }
 
-    pub(crate) fn validate(&self, resources: &ResourceData) -> Result<(), String>
+    pub(crate) fn validate(
+        &self,
+        resources: &ResourceData,
+        services: &ServiceData,
+        tiles: &TileData,
+    ) -> Result<(), String>
     {
         for (id, spec) in self.iter() {
-            spec.validate(id, self, resources)?;
+            spec.validate(id, self, resources, services, tiles)?;
         }
+        self.service_ranks()?;
 
         Ok(())
     }
 
+    /// Ranks services by their dependencies.
+    ///
+    /// A service's rank is 0 if none of its providers require services. Otherwise it is one more than the highest
+    /// rank of the services its providers require. Returns an error if services depend on each other in a cycle.
+    pub(crate) fn service_ranks(&self) -> Result<HashMap<ServiceId, usize>, String>
+    {
+        let mut dependencies = HashMap::<&ServiceId, HashSet<&ServiceId>>::default();
+        for spec in self.values() {
+            let BuildingTypeSpec::Service { service, .. } = &spec.building_type else { continue };
+            let required = dependencies.entry(service).or_default();
+            if let BuildCost::Build { services, .. } = &spec.build_cost {
+                required.extend(services.keys());
+            }
+        }
+
+        // Each pass ranks the services whose dependencies are all ranked.
+        let mut ranks = HashMap::<ServiceId, usize>::with_capacity(dependencies.len());
+        while ranks.len() < dependencies.len() {
+            let mut ranked = Vec::default();
+            for (service, required) in dependencies.iter() {
+                if ranks.contains_key(*service) {
+                    continue;
+                }
+                // Services without providers have rank 0.
+                let required_ranks: Option<Vec<usize>> = required
+                    .iter()
+                    .map(|required| match dependencies.contains_key(*required) {
+                        true => ranks.get(*required).copied(),
+                        false => Some(0),
+                    })
+                    .collect();
+                let Some(required_ranks) = required_ranks else { continue };
+                let rank = required_ranks
+                    .into_iter()
+                    .map(|rank| rank + 1)
+                    .max()
+                    .unwrap_or_default();
+                ranked.push(((*service).clone(), rank));
+            }
+            if ranked.is_empty() {
+                let mut unranked: Vec<&ServiceId> = dependencies
+                    .keys()
+                    .filter(|service| !ranks.contains_key(**service))
+                    .copied()
+                    .collect();
+                unranked.sort_unstable();
+                return Err(format!("BuildingData has services that depend on each other: {:?}", unranked));
+            }
+            ranks.extend(ranked);
+        }
+
+        Ok(ranks)
+    }
+
     pub fn get_tileselect_tiles(&self) -> Option<u16>
     {
         self.get("hq-1").and_then(|spec| match &spec.building_type {
@@ -1,3 +1,7 @@
+mod construction;
 mod data;
+mod proximity;
 
+pub(crate) use construction::*;
 pub use data::*;
+pub use proximity::*;
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Modifies a building's production based on the tiles around it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProximityModifier
+{
+    /// Changes production by a percentage when at least `min_neighbors` adjacent tiles are water tiles.
+    Water
+    {
+        min_neighbors: u8, production_percent: i32
+    },
+}
+
+impl ProximityModifier
+{
+    pub(crate) fn validate(&self, id: &BuildingId) -> Result<(), String>
+    {
+        match self {
+            Self::Water { min_neighbors, production_percent } => {
+                if !(1..=6).contains(min_neighbors) {
+                    return Err(format!(
+                        "{:?} has water proximity modifier with min neighbors {} not between 1 and 6",
+                        id, min_neighbors
+                    ));
+                }
+                if *production_percent < -100 {
+                    return Err(format!(
+                        "{:?} has water proximity modifier with production percent {} below -100",
+                        id, production_percent
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Requirement on a building's surroundings that must be met in order to construct the building.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ProximityRequirement
+{
+    /// The building must be within `distance` hexes of a water tile.
+    Water
+    {
+        distance: u32
+    },
+    /// The building must be within `distance` hexes of a tile of type `tile`.
+    ///
+    /// If `owned` is set, the nearby tile must be owned by the builder.
+    Tile
+    {
+        tile: TileId,
+        distance: u32,
+        #[serde(default)]
+        owned: bool,
+    },
+}
+
+impl ProximityRequirement
+{
+    pub fn distance(&self) -> u32
+    {
+        match self {
+            Self::Water { distance } | Self::Tile { distance, .. } => *distance,
+        }
+    }
+
+    pub(crate) fn validate(&self, id: &BuildingId, tiles: &TileData) -> Result<(), String>
+    {
+        if self.distance() == 0 {
+            return Err(format!("{:?} has proximity requirement with distance 0", id));
+        }
+
+        match self {
+            Self::Water { .. } => {
+                if !tiles.values().any(|spec| spec.is_water_tile) {
+                    return Err(format!("{:?} has water proximity requirement but there are no water tiles", id));
+                }
+            }
+            Self::Tile { tile, owned, .. } => {
+                let Some(spec) = tiles.get(tile) else {
+                    return Err(format!("{:?} has proximity requirement for unregistered tile {:?}", id, tile));
+                };
+                if *owned && !spec.is_ownable {
+                    return Err(format!(
+                        "{:?} has proximity requirement for owned tile {:?} but the tile is not ownable",
+                        id, tile
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts the water tiles adjacent to a tile.
+pub fn count_water_neighbors(tile: Hex, grid: &HexGrid, water: &Query<(), With<WaterTile>>) -> u8
+{
+    tile.all_neighbors()
+        .iter()
+        .filter_map(|neighbor| grid.tiles.get(neighbor))
+        .filter(|entity| water.contains(**entity))
+        .count() as u8
+}
+
+/// Gets the total production modifier of a building as a percentage, given its surroundings.
+///
+/// The modifier will not be lower than -100.
+pub fn production_modifier_percent(spec: &BuildingSpec, water_neighbors: u8) -> i32
+{
+    let percent: i32 = spec
+        .proximity_modifiers
+        .iter()
+        .map(|modifier| match modifier {
+            ProximityModifier::Water { min_neighbors, production_percent } => {
+                if water_neighbors >= *min_neighbors {
+                    *production_percent
+                } else {
+                    0
+                }
+            }
+        })
+        .sum();
+
+    percent.max(-100)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,5 +1,9 @@
+mod player_inventory;
 mod player_map;
 mod player_state;
+mod queued_orders;
 
+pub use player_inventory::*;
 pub use player_map::*;
 pub use player_state::*;
+pub use queued_orders::*;
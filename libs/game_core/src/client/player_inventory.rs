@@ -0,0 +1,150 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starting contents of player inventories.
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StartingInventory
+{
+    #[serde(default)]
+    pub gold: u64,
+    #[serde(default)]
+    pub resources: HashMap<ResourceId, u64>,
+}
+
+impl StartingInventory
+{
+    pub(crate) fn validate(&self, resources: &ResourceData) -> Result<(), String>
+    {
+        for resource_id in self.resources.keys() {
+            if !resources.contains(resource_id) {
+                return Err(format!("StartingInventory has unregistered resource {:?}", resource_id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a player's resources and gold.
+///
+/// Inventories are spawned on their own entities alongside player entities. Visibility is tracked per-entity, so
+/// this lets inventories be replicated only to their owners (and watchers) while player entities are visible to
+/// everyone.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[require(Replicated)]
+pub struct PlayerInventory
+{
+    /// The player who owns this inventory.
+    owner: ClientId,
+    resources: HashMap<ResourceId, u64>,
+    gold: u64,
+}
+
+impl PlayerInventory
+{
+    pub(crate) fn new(owner: ClientId, starting: &StartingInventory) -> Self
+    {
+        Self {
+            owner,
+            resources: starting.resources.clone(),
+            gold: starting.gold,
+        }
+    }
+
+    pub fn owner(&self) -> ClientId
+    {
+        self.owner
+    }
+
+    pub fn gold(&self) -> u64
+    {
+        self.gold
+    }
+
+    /// Gets the amount of a resource in the inventory.
+    pub fn resource(&self, resource_id: &ResourceId) -> u64
+    {
+        self.resources.get(resource_id).copied().unwrap_or_default()
+    }
+
+    pub fn resources(&self) -> &HashMap<ResourceId, u64>
+    {
+        &self.resources
+    }
+
+    /// Removes gold from the inventory.
+    ///
+    /// Returns the shortfall on failure.
+    pub(crate) fn try_spend_gold(&mut self, amount: u64) -> Result<(), u64>
+    {
+        if amount > self.gold {
+            return Err(amount - self.gold);
+        }
+        self.gold -= amount;
+        Ok(())
+    }
+
+    pub(crate) fn add_resource(&mut self, resource_id: &ResourceId, amount: u64)
+    {
+        if amount == 0 {
+            return;
+        }
+        let entry = self.resources.entry(resource_id.clone()).or_default();
+        *entry = entry.saturating_add(amount);
+    }
+
+    /// Gets the resources missing from the inventory in order to pay a cost.
+    ///
+    /// Returns `None` if the cost can be paid.
+    pub fn shortfall(&self, cost: &ResourceCost) -> Option<ResourceCost>
+    {
+        let shortfall: HashMap<ResourceId, u64> = cost
+            .iter()
+            .filter_map(|(resource_id, amount)| {
+                let missing = amount.saturating_sub(self.resource(resource_id));
+                (missing > 0).then(|| (resource_id.clone(), missing))
+            })
+            .collect();
+
+        (!shortfall.is_empty()).then_some(ResourceCost(shortfall))
+    }
+
+    /// Removes resources from the inventory.
+    ///
+    /// Returns the shortfall on failure, in which case nothing is removed.
+    pub(crate) fn try_spend(&mut self, cost: &ResourceCost) -> Result<(), ResourceCost>
+    {
+        if let Some(shortfall) = self.shortfall(cost) {
+            return Err(shortfall);
+        }
+        for (resource_id, amount) in cost.iter() {
+            if let Some(entry) = self.resources.get_mut(resource_id) {
+                *entry -= *amount;
+            }
+        }
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the inventory of a player.
+pub(crate) fn get_inventory_mut<'a>(
+    inventories: &'a mut Query<&mut PlayerInventory>,
+    client_id: ClientId,
+) -> Option<Mut<'a, PlayerInventory>>
+{
+    inventories
+        .iter_mut()
+        .find(|inventory| inventory.owner == client_id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with a player's orders for the current round when rounds use [`RoundResolutionMode::Queued`].
+///
+/// Spawned on its own entity and only replicated to the owner. Orders are applied and cleared at the end of each
+/// round.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[require(Replicated)]
+pub struct QueuedOrders
+{
+    /// The player who owns these orders.
+    owner: ClientId,
+    orders: Vec<PlayerInput>,
+}
+
+impl QueuedOrders
+{
+    pub(crate) fn new(owner: ClientId) -> Self
+    {
+        Self { owner, orders: Vec::default() }
+    }
+
+    pub fn owner(&self) -> ClientId
+    {
+        self.owner
+    }
+
+    /// Orders in the order they will be applied.
+    pub fn orders(&self) -> &[PlayerInput]
+    {
+        &self.orders
+    }
+
+    /// Gets the queued order for a tile.
+    pub fn get(&self, tile: Hex) -> Option<&PlayerInput>
+    {
+        self.orders.iter().find(|order| order.tile() == tile)
+    }
+
+    /// Queues an order. Replaces the existing order for the same tile, if there is one.
+    pub(crate) fn queue(&mut self, order: PlayerInput)
+    {
+        match self
+            .orders
+            .iter_mut()
+            .find(|queued| queued.tile() == order.tile())
+        {
+            Some(queued) => *queued = order,
+            None => self.orders.push(order),
+        }
+    }
+
+    /// Removes the queued order for a tile. Returns `false` if there was no order.
+    pub(crate) fn cancel(&mut self, tile: Hex) -> bool
+    {
+        let prev_len = self.orders.len();
+        self.orders.retain(|order| order.tile() != tile);
+        self.orders.len() != prev_len
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<PlayerInput>
+    {
+        std::mem::take(&mut self.orders)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
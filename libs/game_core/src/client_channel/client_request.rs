@@ -1,14 +1,71 @@
 use bevy_girk_utils::*;
 use bevy_replicon::prelude::Channel;
+use hexx::Hex;
 use serde::{Deserialize, Serialize};
 
+use crate::*;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Player inputs that can be sent to the game.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum PlayerInput
 {
-    Placeholder,
+    /// Select a tile during tile select.
+    SelectTile(Hex),
+    /// Deselect a tile previously selected during tile select.
+    DeselectTile(Hex),
+    /// Claim an unowned tile during play.
+    ClaimTile(Hex),
+    /// Remove a claim on a tile during play.
+    UnclaimTile(Hex),
+    /// Construct a building on an owned tile that doesn't have a building.
+    Build
+    {
+        tile: Hex, building: BuildingId
+    },
+    /// Upgrade the building on an owned tile.
+    Upgrade
+    {
+        tile: Hex, building: BuildingId
+    },
+    /// Demolish the building on an owned tile.
+    Demolish
+    {
+        tile: Hex
+    },
+    /// Cancel the queued order for a tile when rounds use [`RoundResolutionMode::Queued`].
+    CancelOrder
+    {
+        tile: Hex
+    },
+}
+
+impl PlayerInput
+{
+    /// The tile targeted by the input.
+    pub fn tile(&self) -> Hex
+    {
+        match self {
+            Self::SelectTile(tile)
+            | Self::DeselectTile(tile)
+            | Self::ClaimTile(tile)
+            | Self::UnclaimTile(tile)
+            | Self::Build { tile, .. }
+            | Self::Upgrade { tile, .. }
+            | Self::Demolish { tile }
+            | Self::CancelOrder { tile } => *tile,
+        }
+    }
+
+    /// Whether the input is a play action that can be queued when rounds use [`RoundResolutionMode::Queued`].
+    pub fn is_queueable(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::ClaimTile(_) | Self::UnclaimTile(_) | Self::Build { .. } | Self::Upgrade { .. } | Self::Demolish { .. }
+        )
+    }
 }
 
 impl IntoChannel for PlayerInput
@@ -16,7 +73,10 @@ impl IntoChannel for PlayerInput
     fn into_event_type(&self) -> Channel
     {
         match &self {
-            Self::Placeholder => SendUnordered.into(),
+            Self::SelectTile(_) | Self::DeselectTile(_) => SendOrdered.into(),
+            Self::ClaimTile(_) | Self::UnclaimTile(_) => SendOrdered.into(),
+            Self::Build { .. } | Self::Upgrade { .. } | Self::Demolish { .. } => SendOrdered.into(),
+            Self::CancelOrder { .. } => SendOrdered.into(),
         }
     }
 }
@@ -53,7 +113,7 @@ impl CommandInput
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Requests that can be sent to the game.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum ClientRequest
 {
     /// Request the current game state.
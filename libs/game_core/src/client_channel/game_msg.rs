@@ -7,18 +7,76 @@ use crate::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Reasons a game request may be rejected
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RejectionReason
 {
-    ModeMismatch,
+    /// The request can't be handled in the current game phase.
+    WrongPhase,
+    /// The client sent too many requests.
+    RateLimited,
+    /// The tile doesn't exist or can't be owned.
+    InvalidTile,
+    /// The tile isn't owned by the requesting player.
+    TileNotOwned,
+    /// The tile is already owned.
+    TileAlreadyOwned,
+    /// The player can't own any more tiles.
+    TileLimitReached,
+    /// The player already claimed the tile.
+    AlreadyClaimed,
+    /// The player hasn't claimed the tile.
+    NotClaimed,
+    /// The building isn't allowed on the tile.
+    InvalidBuilding,
+    /// The tile already has a building.
+    TileHasBuilding,
+    /// The tile doesn't have a building.
+    NoBuilding,
+    /// The building can't be demolished.
+    Indestructible,
+    /// The tile's surroundings don't meet the building's proximity requirements.
+    ProximityRequirementNotMet,
+    /// The player doesn't have enough resources.
+    InsufficientResources
+    {
+        shortfall: ResourceCost,
+    },
+    /// The player doesn't have enough gold.
+    InsufficientGold
+    {
+        shortfall: u64,
+    },
+    /// The player doesn't have a queued order for the tile.
+    NoQueuedOrder,
+    /// The player can't queue any more orders this round.
+    TooManyOrders,
+    /// The request is invalid for some other reason.
     Invalid,
     None,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Consolidated game state sent to a client when it connects.
+///
+/// Lets reconnecting clients catch up on everything they missed while disconnected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameResync
+{
+    pub state: GameState,
+    pub paused: bool,
+    /// The current round. Zero before the first round starts.
+    pub round: u32,
+    /// Time remaining in tile select or the current round.
+    pub remaining_ms: u128,
+    /// Notifications the client missed while disconnected, in the order they were sent.
+    pub notifications: Vec<GameMsg>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Messages that can be sent out of the game.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameMsg
 {
     RequestRejected
@@ -36,8 +94,53 @@ pub enum GameMsg
         round: u32,
         remaining_ms: u128,
     },
+    /// Outcome of tile claims at the end of a round.
+    TileClaimResults
+    {
+        round: u32,
+        results: Vec<TileClaimResult>,
+    },
+    /// Resources produced for a player at the end of a round.
+    ProductionSummary
+    {
+        round: u32,
+        produced: Vec<(ResourceId, u64)>,
+    },
+    /// Sent periodically during tile select and rounds so clients can correct their timers.
+    ClockSync
+    {
+        /// Game time when the message was sent. Used to discard stale messages.
+        game_time_ms: u128,
+        /// The current round. Zero during tile select.
+        round: u32,
+        /// Time remaining in tile select or the current round.
+        remaining_ms: u128,
+    },
+    /// Outcome of a player's queued orders at the end of a round.
+    OrderResults
+    {
+        round: u32,
+        results: Vec<OrderResult>,
+    },
     Pause,
     Unpause,
+    /// Sent to a client when it connects or reconnects.
+    Resync(GameResync),
+}
+
+impl GameMsg
+{
+    /// Whether the message is a per-player notification that should be buffered for disconnected players.
+    pub fn is_notification(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::RequestRejected { .. }
+                | Self::TileClaimResults { .. }
+                | Self::ProductionSummary { .. }
+                | Self::OrderResults { .. }
+        )
+    }
 }
 
 impl IntoChannel for GameMsg
@@ -49,8 +152,13 @@ impl IntoChannel for GameMsg
             Self::CurrentGameState(_) => SendOrdered.into(),
             Self::TileSelectInfo { .. } => SendOrdered.into(),
             Self::RoundInfo { .. } => SendOrdered.into(),
+            Self::TileClaimResults { .. } => SendOrdered.into(),
+            Self::ProductionSummary { .. } => SendOrdered.into(),
+            Self::ClockSync { .. } => SendUnordered.into(),
+            Self::OrderResults { .. } => SendOrdered.into(),
             Self::Pause => SendOrdered.into(),
             Self::Unpause => SendOrdered.into(),
+            Self::Resync(_) => SendOrdered.into(),
         }
     }
 }
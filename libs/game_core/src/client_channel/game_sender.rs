@@ -0,0 +1,109 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::vis::*;
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recipients of a game message.
+#[derive(Debug, Clone)]
+pub enum GameMsgTarget
+{
+    All,
+    Client(ClientId),
+    Visibility(VisibilityCondition),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records outgoing game messages and the game over report.
+///
+/// Not inserted by [`GamePlugin`]. Insert it to capture game output without connecting clients (e.g. in a
+/// headless simulation).
+#[derive(Resource, Default, Debug)]
+pub struct GameRecorder
+{
+    messages: Vec<(GameMsgTarget, GameMsg)>,
+    game_over_report: Option<ProvGameOverReport>,
+}
+
+impl GameRecorder
+{
+    /// Takes all messages recorded since the last call.
+    pub fn take_messages(&mut self) -> Vec<(GameMsgTarget, GameMsg)>
+    {
+        std::mem::take(&mut self.messages)
+    }
+
+    pub fn game_over_report(&self) -> Option<&ProvGameOverReport>
+    {
+        self.game_over_report.as_ref()
+    }
+
+    pub(crate) fn set_game_over_report(&mut self, report: ProvGameOverReport)
+    {
+        self.game_over_report = Some(report);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends game messages to clients.
+///
+/// Wraps the game framework's sender so messages are also captured by [`GameRecorder`] if it exists. Notifications
+/// sent with [`Self::send_to_client`] or [`Self::send_to_all`] are buffered for disconnected players in
+/// [`PendingNotifications`].
+#[derive(SystemParam)]
+pub(crate) struct GameSender<'w, 's>
+{
+    sender: bevy_girk_game_fw::GameSender<'w, 's>,
+    recorder: Option<ResMut<'w, GameRecorder>>,
+    pending: Option<ResMut<'w, PendingNotifications>>,
+}
+
+impl GameSender<'_, '_>
+{
+    fn record(&mut self, target: GameMsgTarget, msg: &GameMsg)
+    {
+        let Some(recorder) = &mut self.recorder else { return };
+        recorder.messages.push((target, msg.clone()));
+    }
+
+    /// Marks a client as connected and takes the notifications buffered while it was disconnected.
+    pub(crate) fn connect_client(&mut self, client_id: ClientId) -> Vec<GameMsg>
+    {
+        let Some(pending) = &mut self.pending else { return Vec::default() };
+        pending.connect(client_id)
+    }
+
+    /// Sends a message to clients matching the visibility condition.
+    ///
+    /// Messages sent this way are not buffered for disconnected players, since the condition can't be evaluated
+    /// for them. Use [`Self::send_to_client`] or [`Self::send_to_all`] for notifications.
+    pub(crate) fn send(&mut self, msg: GameMsg, condition: VisibilityCondition)
+    {
+        self.record(GameMsgTarget::Visibility(condition.clone()), &msg);
+        self.sender.send(msg, condition);
+    }
+
+    pub(crate) fn send_to_client(&mut self, msg: GameMsg, client_id: ClientId)
+    {
+        self.record(GameMsgTarget::Client(client_id), &msg);
+        if let Some(pending) = &mut self.pending {
+            pending.push(client_id, &msg);
+        }
+        self.sender.send_to_client(msg, client_id);
+    }
+
+    pub(crate) fn send_to_all(&mut self, msg: GameMsg)
+    {
+        self.record(GameMsgTarget::All, &msg);
+        if let Some(pending) = &mut self.pending {
+            pending.push_all(&msg);
+        }
+        self.sender.send_to_all(msg);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -24,21 +24,42 @@ where
 
 /// Handle a request sent to the game from a client.
 ///
-/// Note: this function is meant to be injected to a [`ClientMessageHandler`].
-pub(crate) fn handle_client_request(world: &mut World, id: ClientId, req: ClientRequest)
+/// Requests that exceed the client's [`RateLimitSettings`] are rejected.
+///
+/// Note: this function is meant to be injected to a [`ClientMessageHandler`]. It can also be called directly to
+/// submit requests without a network connection.
+pub fn handle_client_request(world: &mut World, id: ClientId, req: ClientRequest)
 {
+    // Only the first rate-limited request in each abuse window is answered, so abusive clients can't flood the
+    // server with rejections.
+    match world.syscall((id, req.clone()), check_rate_limit) {
+        RateLimitCheck::Allowed => (),
+        RateLimitCheck::Limited => {
+            tracing::debug!("rejecting {req:?} from client {id}; rate limit exceeded");
+            world.syscall((id, req, RejectionReason::RateLimited), notify_request_rejected);
+            return;
+        }
+        RateLimitCheck::Dropped => {
+            tracing::trace!("dropping {req:?} from client {id}; rate limit exceeded");
+            return;
+        }
+    }
+
     let state = world.syscall((), get_game_state);
-    let reject = |world: &mut World| {
-        world.syscall((id, req, RejectionReason::ModeMismatch), notify_request_rejected);
+    let reject = |world: &mut World, req: ClientRequest| {
+        world.syscall((id, req, RejectionReason::WrongPhase), notify_request_rejected);
     };
 
-    match req {
+    match &req {
         ClientRequest::GetGameState => world.syscall(id, handle_game_state_request),
         ClientRequest::PlayerInput(i) => match state {
-            GameState::Play => player_syscall(world, id, req, i, handle_player_input),
-            _ => reject(world),
+            GameState::TileSelect | GameState::Play => {
+                player_syscall(world, id, req.clone(), i.clone(), handle_player_input)
+            }
+            _ => reject(world, req),
         },
         ClientRequest::CommandInput(i) => {
+            let i = *i;
             #[cfg(not(feature = "commands"))]
             {
                 tracing::debug!("ignoring {i:?} from client {id}; commands are disabled");
@@ -46,7 +67,7 @@ pub(crate) fn handle_client_request(world: &mut World, id: ClientId, req: Client
             #[cfg(feature = "commands")]
             match state {
                 GameState::TileSelect | GameState::Play => player_syscall(world, id, req, i, handle_command_input),
-                _ => reject(world),
+                _ => reject(world, req),
             }
         }
     }
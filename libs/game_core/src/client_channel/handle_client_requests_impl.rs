@@ -1,6 +1,5 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
-use bevy_girk_game_fw::*;
 
 use crate::*;
 
@@ -23,13 +22,77 @@ pub(crate) fn handle_game_state_request(In(client_id): In<ClientId>, world: &mut
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Applies a play action.
+///
+/// Used for inputs received during a round, or queued orders resolved at the end of a round.
+pub(crate) fn apply_play_input(
+    world: &mut World,
+    player_entity: Entity,
+    id: ClientId,
+    input: PlayerInput,
+) -> Result<(), RejectionReason>
+{
+    match input {
+        PlayerInput::ClaimTile(tile) => world.syscall((player_entity, id, tile), handle_claim_tile),
+        PlayerInput::UnclaimTile(tile) => world.syscall((player_entity, id, tile), handle_unclaim_tile),
+        PlayerInput::Build { tile, building } => {
+            world.syscall((player_entity, id, (tile, building)), handle_build)
+        }
+        PlayerInput::Upgrade { tile, building } => {
+            world.syscall((player_entity, id, (tile, building)), handle_upgrade)
+        }
+        PlayerInput::Demolish { tile } => world.syscall((player_entity, id, tile), handle_demolish),
+        PlayerInput::SelectTile(_) | PlayerInput::DeselectTile(_) | PlayerInput::CancelOrder { .. } => {
+            Err(RejectionReason::WrongPhase)
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) fn handle_player_input(
-    In((_player_entity, _id, input)): In<(Entity, ClientId, PlayerInput)>,
-    _world: &mut World,
+    In((player_entity, id, input)): In<(Entity, ClientId, PlayerInput)>,
+    world: &mut World,
 )
 {
-    match input {
-        PlayerInput::Placeholder => (),
+    let state = world.syscall((), get_game_state);
+    let queued = world
+        .get_resource::<RoundResolutionSettings>()
+        .is_some_and(|settings| settings.is_queued());
+
+    let result = match (state, input.clone()) {
+        (GameState::TileSelect, PlayerInput::SelectTile(tile)) => {
+            world.syscall((player_entity, id, tile), handle_select_tile)
+        }
+        (GameState::TileSelect, PlayerInput::DeselectTile(tile)) => {
+            world.syscall((player_entity, id, tile), handle_deselect_tile)
+        }
+        (GameState::Play, PlayerInput::CancelOrder { tile }) => {
+            world.syscall((player_entity, id, tile), handle_cancel_order)
+        }
+        (GameState::Play, input) if queued && input.is_queueable() => {
+            world.syscall((player_entity, id, input), handle_queue_order)
+        }
+        (GameState::Play, input) => apply_play_input(world, player_entity, id, input),
+        _ => Err(RejectionReason::WrongPhase),
+    };
+
+    let is_bot = world.get::<Bot>(player_entity).is_some();
+    if result.is_ok() && !is_bot {
+        let time = world.resource::<GameTime>().elapsed();
+        if let Some(mut replay) = world.get_resource_mut::<ReplayRecorder>() {
+            replay.record_request(time, id, ClientRequest::PlayerInput(input.clone()));
+        }
+    }
+
+    if let Err(reason) = result {
+        tracing::debug!(?reason, "rejecting {input:?} from client {id} during {state:?}");
+
+        // Bots aren't connected, so they can't be notified.
+        if is_bot {
+            return;
+        }
+        world.syscall((id, ClientRequest::PlayerInput(input), reason), notify_request_rejected);
     }
 }
 
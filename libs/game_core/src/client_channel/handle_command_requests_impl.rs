@@ -13,6 +13,9 @@ pub(crate) fn handle_command_input(
 )
 {
     tracing::debug!("applying {input:?} from client {id}");
+    if let Some(mut replay) = world.get_resource_mut::<ReplayRecorder>() {
+        replay.record_command();
+    }
 
     match input {
         CommandInput::NextRound => {
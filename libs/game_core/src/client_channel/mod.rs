@@ -1,13 +1,17 @@
 mod client_request;
 mod game_msg;
+mod game_sender;
 mod handle_client_requests;
 mod handle_client_requests_impl;
 #[cfg(feature = "commands")]
 mod handle_command_requests_impl;
+mod rate_limit;
 
 pub use client_request::*;
 pub use game_msg::*;
-pub(crate) use handle_client_requests::*;
-pub(self) use handle_client_requests_impl::*;
+pub use game_sender::*;
+pub use handle_client_requests::*;
+pub(crate) use handle_client_requests_impl::*;
 #[cfg(feature = "commands")]
 pub(self) use handle_command_requests_impl::*;
+pub use rate_limit::*;
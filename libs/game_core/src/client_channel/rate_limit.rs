@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon::shared::backend::connected_client::NetworkId;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Token bucket settings for one type of request.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct TokenBucketConfig
+{
+    /// Maximum number of requests that can be sent in a burst.
+    pub capacity: u32,
+    /// Number of requests that become available each second.
+    pub refill_per_sec: f32,
+}
+
+impl TokenBucketConfig
+{
+    fn validate(&self, name: &str) -> Result<(), String>
+    {
+        if self.capacity == 0 {
+            return Err(format!("RateLimitSettings {name} capacity is zero"));
+        }
+        if !self.refill_per_sec.is_finite() || self.refill_per_sec <= 0. {
+            return Err(format!(
+                "RateLimitSettings {name} refill rate {} is not positive",
+                self.refill_per_sec
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for limiting how often clients can send requests to the game.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitSettings
+{
+    pub enabled: bool,
+    /// Limit for [`ClientRequest::GetGameState`].
+    pub game_state: TokenBucketConfig,
+    /// Limit for [`ClientRequest::PlayerInput`].
+    pub player_input: TokenBucketConfig,
+    /// Limit for [`ClientRequest::CommandInput`].
+    pub command_input: TokenBucketConfig,
+    /// Rate-limited requests are counted in windows of this many seconds.
+    pub abuse_window_secs: f32,
+    /// Number of rate-limited requests in one window before the client is logged as abusive.
+    pub abuse_warn_threshold: u32,
+    /// Number of rate-limited requests in one window before the client is disconnected.
+    pub abuse_disconnect_threshold: u32,
+}
+
+impl RateLimitSettings
+{
+    pub(crate) fn validate(&self) -> Result<(), String>
+    {
+        self.game_state.validate("game state")?;
+        self.player_input.validate("player input")?;
+        self.command_input.validate("command input")?;
+        if !self.abuse_window_secs.is_finite() || self.abuse_window_secs <= 0. {
+            return Err(format!(
+                "RateLimitSettings abuse window {} is not positive",
+                self.abuse_window_secs
+            ));
+        }
+        if self.abuse_warn_threshold > self.abuse_disconnect_threshold {
+            return Err(format!(
+                "RateLimitSettings abuse warn threshold {} is above the disconnect threshold {}",
+                self.abuse_warn_threshold, self.abuse_disconnect_threshold
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn bucket_config(&self, request: &ClientRequest) -> TokenBucketConfig
+    {
+        match request {
+            ClientRequest::GetGameState => self.game_state,
+            ClientRequest::PlayerInput(_) => self.player_input,
+            ClientRequest::CommandInput(_) => self.command_input,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct TokenBucket
+{
+    tokens: f32,
+    last_refill: Duration,
+}
+
+impl TokenBucket
+{
+    fn new(config: TokenBucketConfig, now: Duration) -> Self
+    {
+        Self { tokens: config.capacity as f32, last_refill: now }
+    }
+
+    /// Refills the bucket, then takes a token if one is available.
+    fn try_take(&mut self, config: TokenBucketConfig, now: Duration) -> bool
+    {
+        let elapsed = now.saturating_sub(self.last_refill).as_secs_f32();
+        self.tokens = (self.tokens + elapsed * config.refill_per_sec).min(config.capacity as f32);
+        self.last_refill = now;
+
+        if self.tokens < 1. {
+            return false;
+        }
+        self.tokens -= 1.;
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks rate limits for one client.
+///
+/// Kept when the client disconnects so reconnecting doesn't reset its limits.
+#[derive(Debug, Default)]
+struct ClientRateLimits
+{
+    buckets: HashMap<&'static str, TokenBucket>,
+    /// Start of the current abuse window. Windows start at the first violation after the previous window ended.
+    window_start: Option<Duration>,
+    violations: u32,
+}
+
+impl ClientRateLimits
+{
+    /// Records a rate-limited request. Returns the number of violations in the current window.
+    fn add_violation(&mut self, window: Duration, now: Duration) -> u32
+    {
+        if self
+            .window_start
+            .is_none_or(|start| now.saturating_sub(start) > window)
+        {
+            self.window_start = Some(now);
+            self.violations = 0;
+        }
+        self.violations += 1;
+        self.violations
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks request token buckets for each client.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct RequestRateLimiter
+{
+    clients: HashMap<ClientId, ClientRateLimits>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Result of checking a request against the client's rate limits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RateLimitCheck
+{
+    Allowed,
+    /// The request exceeded the limit and is the client's first violation in the current abuse window, so the
+    /// client should be told it was rejected.
+    Limited,
+    /// The request exceeded the limit and the client was already told in the current abuse window.
+    Dropped,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn request_kind(request: &ClientRequest) -> &'static str
+{
+    match request {
+        ClientRequest::GetGameState => "game state",
+        ClientRequest::PlayerInput(_) => "player input",
+        ClientRequest::CommandInput(_) => "command input",
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Takes a token for the request from the client's bucket.
+///
+/// Clients that keep exceeding the limit are logged, then disconnected.
+pub(crate) fn check_rate_limit(
+    In((client_id, request)): In<(ClientId, ClientRequest)>,
+    mut c: Commands,
+    time: Res<Time>,
+    settings: Option<Res<RateLimitSettings>>,
+    mut limiter: ResMut<RequestRateLimiter>,
+    clients: Query<(Entity, &NetworkId)>,
+) -> RateLimitCheck
+{
+    let Some(settings) = settings.filter(|settings| settings.enabled) else { return RateLimitCheck::Allowed };
+    let now = time.elapsed();
+    let config = settings.bucket_config(&request);
+    let kind = request_kind(&request);
+
+    let limits = limiter.clients.entry(client_id).or_default();
+    let bucket = limits
+        .buckets
+        .entry(kind)
+        .or_insert_with(|| TokenBucket::new(config, now));
+    if bucket.try_take(config, now) {
+        return RateLimitCheck::Allowed;
+    }
+
+    let window = Duration::from_secs_f32(settings.abuse_window_secs);
+    let violations = limits.add_violation(window, now);
+    if violations == settings.abuse_warn_threshold {
+        tracing::warn!("client {client_id} exceeded the {kind} rate limit {violations} times in {window:?}");
+    }
+    if violations >= settings.abuse_disconnect_threshold {
+        tracing::warn!("disconnecting client {client_id} for exceeding request rate limits");
+        if let Some((client_entity, _)) = clients.iter().find(|(_, id)| id.get() == client_id) {
+            c.send_event(DisconnectRequest { client_entity });
+        }
+    }
+
+    match violations {
+        1 => RateLimitCheck::Limited,
+        _ => RateLimitCheck::Dropped,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const CONFIG: TokenBucketConfig = TokenBucketConfig { capacity: 3, refill_per_sec: 2.0 };
+
+    fn secs(secs: f32) -> Duration
+    {
+        Duration::from_secs_f32(secs)
+    }
+
+    #[test]
+    fn token_bucket_allows_burst_then_refills()
+    {
+        let mut bucket = TokenBucket::new(CONFIG, secs(0.));
+        for _ in 0..CONFIG.capacity {
+            assert!(bucket.try_take(CONFIG, secs(0.)));
+        }
+        assert!(!bucket.try_take(CONFIG, secs(0.)));
+
+        // Half a second refills one token.
+        assert!(!bucket.try_take(CONFIG, secs(0.25)));
+        assert!(bucket.try_take(CONFIG, secs(0.5)));
+        assert!(!bucket.try_take(CONFIG, secs(0.5)));
+    }
+
+    #[test]
+    fn token_bucket_refill_is_capped()
+    {
+        let mut bucket = TokenBucket::new(CONFIG, secs(0.));
+        assert!(bucket.try_take(CONFIG, secs(0.)));
+
+        // A long idle period only refills up to capacity.
+        let now = secs(100.);
+        for _ in 0..CONFIG.capacity {
+            assert!(bucket.try_take(CONFIG, now));
+        }
+        assert!(!bucket.try_take(CONFIG, now));
+    }
+
+    #[test]
+    fn first_window_starts_at_first_violation()
+    {
+        let window = secs(10.);
+        let mut limits = ClientRateLimits::default();
+
+        // The window isn't anchored at app start, so it lasts until 15s instead of 10s.
+        assert_eq!(limits.add_violation(window, secs(5.)), 1);
+        assert_eq!(limits.add_violation(window, secs(12.)), 2);
+        assert_eq!(limits.add_violation(window, secs(15.)), 3);
+        assert_eq!(limits.add_violation(window, secs(15.5)), 1);
+    }
+
+    #[test]
+    fn violations_reset_after_window()
+    {
+        let window = secs(10.);
+        let mut limits = ClientRateLimits::default();
+        assert_eq!(limits.add_violation(window, secs(15.)), 1);
+        assert_eq!(limits.add_violation(window, secs(20.)), 2);
+        assert_eq!(limits.add_violation(window, secs(25.)), 3);
+
+        // The window restarts at the first violation after it ends.
+        assert_eq!(limits.add_violation(window, secs(25.5)), 1);
+        assert_eq!(limits.add_violation(window, secs(35.5)), 2);
+        assert_eq!(limits.add_violation(window, secs(36.)), 1);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, VecDeque};
+
 use bevy::prelude::*;
 use bevy_replicon::shared::backend::connected_client::NetworkId;
 
@@ -6,6 +8,85 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Maximum number of notifications buffered for each disconnected player. The oldest are dropped first.
+const MAX_PENDING_NOTIFICATIONS: usize = 32;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct PlayerNotifications
+{
+    connected: bool,
+    pending: VecDeque<GameMsg>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buffers notifications sent to players while they are disconnected.
+///
+/// Buffered notifications are included in the [`GameResync`] sent when the player reconnects.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct PendingNotifications
+{
+    players: HashMap<ClientId, PlayerNotifications>,
+}
+
+impl PendingNotifications
+{
+    pub(crate) fn new(players: impl IntoIterator<Item = ClientId>) -> Self
+    {
+        Self {
+            players: players
+                .into_iter()
+                .map(|client_id| (client_id, PlayerNotifications::default()))
+                .collect(),
+        }
+    }
+
+    /// Buffers a notification for one player if they are disconnected.
+    pub(crate) fn push(&mut self, client_id: ClientId, msg: &GameMsg)
+    {
+        let Some(player) = self.players.get_mut(&client_id) else { return };
+        Self::push_inner(player, msg);
+    }
+
+    /// Buffers a notification for all disconnected players.
+    pub(crate) fn push_all(&mut self, msg: &GameMsg)
+    {
+        for player in self.players.values_mut() {
+            Self::push_inner(player, msg);
+        }
+    }
+
+    fn push_inner(player: &mut PlayerNotifications, msg: &GameMsg)
+    {
+        if player.connected || !msg.is_notification() {
+            return;
+        }
+        if player.pending.len() >= MAX_PENDING_NOTIFICATIONS {
+            player.pending.pop_front();
+        }
+        player.pending.push_back(msg.clone());
+    }
+
+    /// Marks a player as connected and takes their buffered notifications.
+    pub(crate) fn connect(&mut self, client_id: ClientId) -> Vec<GameMsg>
+    {
+        let Some(player) = self.players.get_mut(&client_id) else { return Vec::default() };
+        player.connected = true;
+        player.pending.drain(..).collect()
+    }
+
+    fn disconnect(&mut self, client_id: ClientId)
+    {
+        let Some(player) = self.players.get_mut(&client_id) else { return };
+        player.connected = false;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends a [`GameResync`] to clients when they connect, so reconnecting clients can rebuild their state.
 fn handle_client_connect(
     event: Trigger<OnAdd, NetworkId>,
     ids: Query<&NetworkId>,
@@ -18,20 +99,44 @@ fn handle_client_connect(
     let Ok(id) = ids.get(event.target()) else { return };
     let client_id = id.get();
 
-    match **state {
-        GameState::Startup | GameState::Init => (),
-        GameState::TileSelect => {
-            if let Some(remaining_ms) = ctx.duration_config.select_remaining_ms(time.elapsed()) {
-                sender.send(GameMsg::TileSelectInfo { remaining_ms }, vis!(Client(client_id)));
-            }
-        }
-        GameState::Play => {
-            if let Some((round, remaining_ms)) = ctx.duration_config.round_and_remaining_ms(time.elapsed()) {
-                sender.send(GameMsg::RoundInfo { round, remaining_ms }, vis!(Client(client_id)));
-            }
-        }
-        GameState::End => (),
-    }
+    let elapsed = time.elapsed();
+    let (round, remaining_ms) = match **state {
+        GameState::Startup | GameState::Init => (0, 0),
+        GameState::TileSelect => (
+            0,
+            ctx.duration_config
+                .select_remaining_ms(elapsed)
+                .unwrap_or_default(),
+        ),
+        GameState::Play | GameState::End => ctx
+            .duration_config
+            .round_and_remaining_ms(elapsed)
+            .unwrap_or_default(),
+    };
+    let notifications = sender.connect_client(client_id);
+
+    tracing::debug!("resyncing client {client_id} with {} pending notifications", notifications.len());
+    let resync = GameResync {
+        state: **state,
+        paused: time.is_paused(),
+        round,
+        remaining_ms,
+        notifications,
+    };
+    sender.send(GameMsg::Resync(resync), vis!(Client(client_id)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_client_disconnect(
+    event: Trigger<OnRemove, NetworkId>,
+    ids: Query<&NetworkId>,
+    pending: Option<ResMut<PendingNotifications>>,
+)
+{
+    let Ok(id) = ids.get(event.target()) else { return };
+    let Some(mut pending) = pending else { return };
+    pending.disconnect(id.get());
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -42,7 +147,71 @@ impl Plugin for ClientConnectPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_observer(handle_client_connect);
+        app.add_observer(handle_client_connect)
+            .add_observer(handle_client_disconnect);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn notification(round: u32) -> GameMsg
+    {
+        GameMsg::ProductionSummary { round, produced: Vec::default() }
+    }
+
+    fn rounds(msgs: &[GameMsg]) -> Vec<u32>
+    {
+        msgs.iter()
+            .map(|msg| match msg {
+                GameMsg::ProductionSummary { round, .. } => *round,
+                _ => panic!("unexpected message {msg:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn buffers_while_disconnected()
+    {
+        let mut pending = PendingNotifications::new([1, 2]);
+
+        // Players start disconnected.
+        pending.push(1, &notification(1));
+        pending.push_all(&notification(2));
+        pending.push(3, &notification(3));
+        pending.push_all(&GameMsg::Pause);
+        assert_eq!(rounds(&pending.connect(1)), vec![1, 2]);
+
+        // Connected players don't buffer.
+        pending.push(1, &notification(4));
+        pending.push_all(&notification(5));
+        assert!(pending.connect(1).is_empty());
+        assert_eq!(rounds(&pending.connect(2)), vec![2, 5]);
+
+        // Buffering resumes after disconnecting.
+        pending.disconnect(1);
+        pending.push(1, &notification(6));
+        assert_eq!(rounds(&pending.connect(1)), vec![6]);
+        assert!(pending.connect(3).is_empty());
+    }
+
+    #[test]
+    fn drops_oldest_past_cap()
+    {
+        let mut pending = PendingNotifications::new([1]);
+        let total = MAX_PENDING_NOTIFICATIONS as u32 + 5;
+        for round in 0..total {
+            pending.push(1, &notification(round));
+        }
+
+        let buffered = rounds(&pending.connect(1));
+        assert_eq!(buffered.len(), MAX_PENDING_NOTIFICATIONS);
+        assert_eq!(buffered.first(), Some(&5));
+        assert_eq!(buffered.last(), Some(&(total - 1)));
     }
 }
 
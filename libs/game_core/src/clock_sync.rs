@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game time between clock sync messages.
+const CLOCK_SYNC_INTERVAL: Duration = Duration::from_secs(5);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Periodically sends the remaining time in the current phase so clients can correct their timers.
+///
+/// Game time doesn't advance while paused, so no messages are sent until the game is unpaused.
+fn send_clock_sync(
+    mut last_sync: Local<Option<Duration>>,
+    ctx: Res<GameContext>,
+    state: Res<State<GameState>>,
+    time: Res<GameTime>,
+    mut sender: GameSender,
+)
+{
+    let elapsed = time.elapsed();
+    if last_sync.is_some_and(|last_sync| elapsed.saturating_sub(last_sync) < CLOCK_SYNC_INTERVAL) {
+        return;
+    }
+
+    let (round, remaining_ms) = match **state {
+        GameState::TileSelect => {
+            let Some(remaining_ms) = ctx.duration_config.select_remaining_ms(elapsed) else { return };
+            (0, remaining_ms)
+        }
+        GameState::Play => {
+            let Some((round, remaining_ms)) = ctx.duration_config.round_and_remaining_ms(elapsed) else {
+                return;
+            };
+            (round, remaining_ms)
+        }
+        GameState::Startup | GameState::Init | GameState::End => return,
+    };
+
+    *last_sync = Some(elapsed);
+    sender.send_to_all(GameMsg::ClockSync { game_time_ms: elapsed.as_millis(), round, remaining_ms });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ClockSyncPlugin;
+
+impl Plugin for ClockSyncPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(Update, send_clock_sync.in_set(PostInitSet).after(TimeUpdateSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
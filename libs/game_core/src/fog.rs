@@ -0,0 +1,202 @@
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon::shared::backend::connected_client::NetworkId;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::vis::*;
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for fog of war.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct FogOfWarSettings
+{
+    /// If disabled, all tiles are visible to all players.
+    pub enabled: bool,
+    /// Players can see tiles within this hex distance of their owned tiles.
+    pub owned_tile_sight: u32,
+    /// Players can see tiles within this hex distance of their buildings.
+    pub building_sight: u32,
+}
+
+impl FogOfWarSettings
+{
+    pub(crate) fn validate(&self) -> Result<(), String>
+    {
+        if self.enabled && self.owned_tile_sight == 0 && self.building_sight == 0 {
+            return Err("FogOfWarSettings is enabled but all sight ranges are zero".into());
+        }
+
+        Ok(())
+    }
+
+    /// Gets the visibility condition for a public tile meta entity.
+    pub(crate) fn tile_visibility(&self, tile: Hex) -> VisibilityCondition
+    {
+        match self.enabled {
+            true => vis!(any!(InSight::new(tile), Watcher)),
+            false => vis!(Global),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Visibility attribute for clients that can see a tile.
+#[derive(VisibilityAttribute, Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InSight(i32, i32);
+
+impl InSight
+{
+    pub fn new(tile: Hex) -> Self
+    {
+        Self(tile.x, tile.y)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the tiles currently in sight of a player.
+///
+/// Spawned on its own entity and only replicated to the owner and watchers.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[require(Replicated)]
+pub struct PlayerSight
+{
+    owner: ClientId,
+    tiles: HashSet<Hex>,
+}
+
+impl PlayerSight
+{
+    pub(crate) fn new(owner: ClientId) -> Self
+    {
+        Self { owner, tiles: HashSet::default() }
+    }
+
+    pub fn owner(&self) -> ClientId
+    {
+        self.owner
+    }
+
+    pub fn tiles(&self) -> &HashSet<Hex>
+    {
+        &self.tiles
+    }
+
+    pub fn contains(&self, tile: &Hex) -> bool
+    {
+        self.tiles.contains(tile)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recalculates player sight when territory changes, and updates client visibility attributes to match.
+fn update_player_sight(
+    settings: Res<FogOfWarSettings>,
+    grid: Res<HexGrid>,
+    changed: Query<(), (With<TileMeta>, Or<(Changed<TileOwner>, Changed<BuildingId>)>)>,
+    mut removed_owners: RemovedComponents<TileOwner>,
+    mut removed_buildings: RemovedComponents<BuildingId>,
+    owned: Query<(&TileMeta, &TileOwner, Has<BuildingId>)>,
+    mut sights: Query<&mut PlayerSight>,
+    mut attributes: ClientAttributes,
+)
+{
+    let removed_owner = removed_owners.read().count() > 0;
+    let removed_building = removed_buildings.read().count() > 0;
+    if changed.is_empty() && !removed_owner && !removed_building {
+        return;
+    }
+
+    let mut sight = HashMap::<ClientId, HashSet<Hex>>::default();
+    for (meta, owner, has_building) in owned.iter() {
+        let radius = match has_building {
+            true => settings.owned_tile_sight.max(settings.building_sight),
+            false => settings.owned_tile_sight,
+        };
+        sight.entry(owner.0).or_default().extend(
+            meta.tile
+                .range(radius)
+                .filter(|tile| grid.tiles.contains_key(tile)),
+        );
+    }
+
+    for mut player_sight in sights.iter_mut() {
+        let owner = player_sight.owner;
+        let new_sight = sight.remove(&owner).unwrap_or_default();
+        if new_sight == player_sight.tiles {
+            continue;
+        }
+
+        for tile in new_sight.difference(&player_sight.tiles) {
+            attributes.add(owner, InSight::new(*tile));
+        }
+        for tile in player_sight.tiles.difference(&new_sight) {
+            attributes.remove(owner, InSight::new(*tile));
+        }
+        player_sight.tiles = new_sight;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets the visibility of new tile metas.
+///
+/// Selectable tiles are only visible to the selecting player (and watchers), so they are skipped.
+fn set_tile_visibility(
+    event: Trigger<OnAdd, TileMeta>,
+    mut c: Commands,
+    settings: Res<FogOfWarSettings>,
+    metas: Query<&TileMeta, Without<SelectableTile>>,
+)
+{
+    let Ok(meta) = metas.get(event.target()) else { return };
+    c.entity(event.target())
+        .insert(settings.tile_visibility(meta.tile));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Restores a reconnecting client's sight, since client attributes are cleared on disconnect.
+fn restore_sight_on_connect(
+    event: Trigger<OnAdd, NetworkId>,
+    ids: Query<&NetworkId>,
+    sights: Query<&PlayerSight>,
+    mut attributes: ClientAttributes,
+)
+{
+    let Ok(id) = ids.get(event.target()) else { return };
+    let client_id = id.get();
+    let Some(sight) = sights.iter().find(|sight| sight.owner == client_id) else { return };
+
+    for tile in sight.tiles.iter() {
+        attributes.add(client_id, InSight::new(*tile));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct FogOfWarPlugin;
+
+impl Plugin for FogOfWarPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        // - Runs in all game states so sight is available as soon as HQs are placed.
+        app.add_observer(set_tile_visibility)
+            .add_observer(restore_sight_on_connect)
+            .add_systems(
+                Update,
+                update_player_sight
+                    .run_if(|settings: Res<FogOfWarSettings>| settings.enabled)
+                    .after(GameLogicSet::Update),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
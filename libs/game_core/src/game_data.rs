@@ -1,16 +1,26 @@
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utils::RootConfigs;
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GameData
 {
     pub mapgen_settings: MapGenSettings,
+    pub start_region_settings: StartRegionSettings,
+    pub claim_settings: TileClaimSettings,
+    pub round_resolution_settings: RoundResolutionSettings,
+    pub transport_settings: TransportSettings,
+    pub fog_of_war_settings: FogOfWarSettings,
+    pub replay_settings: ReplaySettings,
+    pub snapshot_settings: SnapshotSettings,
+    pub rate_limit_settings: RateLimitSettings,
+    pub starting_inventory: StartingInventory,
     pub resources: ResourceData,
+    pub services: ServiceData,
     pub tiles: TileData,
     pub buildings: BuildingData,
 }
@@ -21,7 +31,18 @@ impl GameData
     {
         let data = Self {
             mapgen_settings: configs.get_type::<MapGenSettings>("game", "MAPGEN_SETTINGS")?,
+            start_region_settings: configs.get_type::<StartRegionSettings>("game", "START_REGION_SETTINGS")?,
+            claim_settings: configs.get_type::<TileClaimSettings>("game", "TILE_CLAIM_SETTINGS")?,
+            round_resolution_settings: configs
+                .get_type::<RoundResolutionSettings>("game", "ROUND_RESOLUTION_SETTINGS")?,
+            transport_settings: configs.get_type::<TransportSettings>("game", "TRANSPORT_SETTINGS")?,
+            fog_of_war_settings: configs.get_type::<FogOfWarSettings>("game", "FOG_OF_WAR_SETTINGS")?,
+            replay_settings: configs.get_type::<ReplaySettings>("game", "REPLAY_SETTINGS")?,
+            snapshot_settings: configs.get_type::<SnapshotSettings>("game", "SNAPSHOT_SETTINGS")?,
+            rate_limit_settings: configs.get_type::<RateLimitSettings>("game", "RATE_LIMIT_SETTINGS")?,
+            starting_inventory: configs.get_type::<StartingInventory>("game", "STARTING_INVENTORY")?,
             resources: ResourceData::new(),
+            services: ServiceData::new(configs)?,
             tiles: TileData::new(configs)?,
             buildings: BuildingData::new(configs)?,
         };
@@ -32,18 +53,51 @@ impl GameData
     }
 
     /// Destructures the game data into resources.
+    ///
+    /// Also inserts a [`ReplayRecorder`] with a copy of the data if replays are enabled, and a
+    /// [`SnapshotRecorder`] if snapshots are enabled.
     pub fn insert(self, world: &mut World)
     {
+        if self.replay_settings.enabled {
+            world.insert_resource(ReplayRecorder::new(self.clone()));
+        }
+        if self.snapshot_settings.enabled {
+            match config_hash(&self) {
+                Ok(hash) => world.insert_resource(SnapshotRecorder::new(hash)),
+                Err(err) => tracing::error!("failed setting up snapshots: {err}"),
+            }
+        }
         world.insert_resource(self.mapgen_settings);
+        world.insert_resource(self.start_region_settings);
+        world.insert_resource(self.claim_settings);
+        world.insert_resource(self.round_resolution_settings);
+        world.insert_resource(self.transport_settings);
+        world.insert_resource(self.fog_of_war_settings);
+        world.insert_resource(self.replay_settings);
+        world.insert_resource(self.snapshot_settings);
+        world.insert_resource(self.rate_limit_settings);
+        world.insert_resource(self.starting_inventory);
+        world.insert_resource(self.services);
         world.insert_resource(self.tiles);
         world.insert_resource(self.buildings);
     }
 
-    fn validate(&self) -> Result<(), String>
+    /// Checks that the game data is consistent.
+    pub fn validate(&self) -> Result<(), String>
     {
         self.resources.validate()?;
-        self.buildings.validate(&self.resources)?;
+        self.round_resolution_settings.validate()?;
+        self.transport_settings.validate()?;
+        self.fog_of_war_settings.validate()?;
+        self.rate_limit_settings.validate()?;
+        self.starting_inventory.validate(&self.resources)?;
+        self.services.validate()?;
+        self.buildings
+            .validate(&self.resources, &self.services, &self.tiles)?;
         self.tiles.validate(&self.buildings)?;
+        self.mapgen_settings.validate(&self.tiles)?;
+        self.start_region_settings
+            .validate(&self.tiles, &self.buildings)?;
         Ok(())
     }
 }
@@ -7,18 +7,38 @@ use crate::*;
 
 /// The game's deterministic random number generator
 #[derive(Resource)]
-pub struct GameRand(Rand64);
+pub struct GameRand
+{
+    rand: Rand64,
+    /// Number of values drawn so far. Saved in snapshots so resumed games continue the same sequence.
+    draws: u64,
+}
 
 impl GameRand
 {
     pub fn new(seed: u128) -> GameRand
     {
-        GameRand(Rand64::new("providence", seed))
+        GameRand { rand: Rand64::new("providence", seed), draws: 0 }
     }
 
     pub fn next(&mut self) -> u64
     {
-        self.0.next()
+        self.draws += 1;
+        self.rand.next()
+    }
+
+    /// Number of values drawn so far.
+    pub fn draws(&self) -> u64
+    {
+        self.draws
+    }
+
+    /// Draws values until `draws` values have been drawn in total.
+    pub(crate) fn fast_forward(&mut self, draws: u64)
+    {
+        while self.draws < draws {
+            self.next();
+        }
     }
 }
 
@@ -34,3 +54,27 @@ pub fn make_player_rand(domain_sep: &str, seed: u64, player_id: PlayerId) -> Ran
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn fast_forward_continues_sequence()
+    {
+        let mut original = GameRand::new(42);
+        for _ in 0..10 {
+            original.next();
+        }
+
+        let mut resumed = GameRand::new(42);
+        resumed.fast_forward(original.draws());
+        assert_eq!(resumed.draws(), 10);
+        for _ in 0..10 {
+            assert_eq!(resumed.next(), original.next());
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
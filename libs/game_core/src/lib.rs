@@ -1,40 +1,64 @@
+mod bots;
 mod buildings;
 mod client;
 mod client_channel;
 mod client_connect;
+mod clock_sync;
+mod fog;
 mod game_data;
 mod game_rand;
 mod map;
 mod meta;
 mod plugin;
 mod prng;
+mod production;
+mod replay;
 mod resources;
+mod round_resolution;
 mod rounds;
+mod services;
 mod sets;
 mod setup;
+mod snapshot;
+mod start_regions;
 mod states;
+mod tile_claims;
+mod tile_select;
 mod time;
+mod transport;
+mod watchers;
 
-/// Re-export
-pub(crate) use bevy_girk_game_fw::GameSender;
+pub use bots::*;
 pub use buildings::*;
 pub use client::*;
 pub use client_channel::*;
 pub(crate) use client_connect::*;
+pub(crate) use clock_sync::*;
+pub use fog::*;
 pub use game_data::*;
 pub use game_rand::*;
 pub use map::*;
 pub use meta::*;
 pub use plugin::*;
 pub use prng::*;
+pub(crate) use production::*;
 /// Re-export
 pub use renet2::ClientId;
+pub use replay::*;
 pub use resources::*;
+pub use round_resolution::*;
 pub(crate) use rounds::*;
+pub use services::*;
 pub use sets::*;
 pub(crate) use setup::*;
+pub use snapshot::*;
+pub use start_regions::*;
 pub use states::*;
+pub use tile_claims::*;
+pub(crate) use tile_select::*;
 pub use time::*;
+pub use transport::*;
+pub use watchers::*;
 /// Re-export
 pub(crate) mod vis
 {
@@ -2,7 +2,7 @@ use std::borrow::{Borrow, Cow};
 
 use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utils::RootConfigs;
 
 use crate::*;
@@ -10,7 +10,7 @@ use crate::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component with the canonical ID of a type of tile.
-#[derive(Component, Debug, Clone, Deserialize, Eq, PartialEq, Hash, Reflect)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Hash, Deserialize)]
 #[component(immutable)]
 pub struct TileId(Cow<'static, str>);
@@ -38,24 +38,39 @@ impl Borrow<str> for TileId
 
 //-------------------------------------------------------------------------------------------------------------------
 
-// TODO: add config for 'minimum distance to nearest tile of same tile'? may be hard to enforce this efficiently
-// - may be useful to improve competitive fairness regarding hyperium locations
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TileSpec
 {
     pub is_ownable: bool,
     /// Used to determine the frequency this tile is generated. Proportional to sum of all tile mapgen factors.
     pub mapgen_factor: u64,
     pub builds_into: Vec<BuildingId>,
-    /// Special flag for marking water tiles which can have proximity effects.
+    /// Special flag for marking water tiles which can have proximity effects (see [`ProximityModifier::Water`]).
     #[serde(default)]
     pub is_water_tile: bool,
     #[serde(default)]
     pub is_edge_tile: bool,
+    /// Target number of tiles in clusters of this tile when terrain is clustered (see
+    /// [`TerrainMode::Clustered`]).
+    ///
+    /// Tiles with a cluster size of 1 are not clustered.
+    #[serde(default = "TileSpec::default_cluster_size")]
+    pub cluster_size: u16,
+    /// Minimum hex distance between tiles of this type.
+    ///
+    /// Useful for competitive fairness around rare tiles. Tiles that are too close to another tile of the same
+    /// type are replaced during mapgen.
+    #[serde(default)]
+    pub min_same_tile_distance: Option<u32>,
 }
 
 impl TileSpec
 {
+    fn default_cluster_size() -> u16
+    {
+        1
+    }
+
     fn validate(&self, id: &TileId, buildings: &BuildingData) -> Result<(), String>
     {
         if !self.is_ownable && !self.builds_into.is_empty() {
@@ -75,6 +90,20 @@ impl TileSpec
         if self.mapgen_factor == 0 {
             return Err(format!("{:?} has spec with a mapgen factor of 0", id));
         }
+        if self.cluster_size == 0 {
+            return Err(format!("{:?} has spec with a cluster size of 0", id));
+        }
+        if let Some(min_distance) = self.min_same_tile_distance {
+            if min_distance < 2 {
+                return Err(format!("{:?} has spec with a min same-tile distance less than 2", id));
+            }
+            if self.cluster_size > 1 {
+                return Err(format!("{:?} has spec with a min same-tile distance but is also clustered", id));
+            }
+            if self.is_edge_tile {
+                return Err(format!("{:?} has spec with a min same-tile distance but is the edge tile", id));
+            }
+        }
 
         Ok(())
     }
@@ -82,7 +111,7 @@ impl TileSpec
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Resource, Debug, Deserialize, Clone, Deref)]
+#[derive(Resource, Debug, Serialize, Deserialize, Clone, Deref)]
 pub struct TileData(HashMap<TileId, TileSpec>);
 
 impl TileData
@@ -103,6 +132,13 @@ impl TileData
             spec.validate(id, buildings)?;
         }
 
+        if self
+            .values()
+            .all(|spec| spec.min_same_tile_distance.is_some())
+        {
+            return Err("tile data does not have any tile specs without a min same-tile distance".into());
+        }
+
         if edge_count != 1 {
             return Err(
                 format!("tile data does not have exactly one tile spec marked as an edge tile; \
@@ -1,3 +1,6 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
 use bevy_girk_utils::Rand64;
@@ -8,6 +11,13 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Number of cellular automaton passes used to smooth clustered terrain (see [`smooth_clusters`]).
+const CLUSTER_SMOOTHING_PASSES: usize = 2;
+/// Number of neighbors of the same clustered tile type needed for a tile to join their cluster.
+const CLUSTER_FILL_THRESHOLD: usize = 4;
+
+//-------------------------------------------------------------------------------------------------------------------
+
 struct MapGenTile<'a>
 {
     id: TileId,
@@ -17,85 +27,303 @@ struct MapGenTile<'a>
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Checks if a tile is on the edge of the map using its index in a rectangular `hexx` grid.
-fn is_edge_tile(map_dimension: i32, edge_buffer: i32, i: i32) -> bool
+/// Finds tiles within `edge_buffer` tiles of the boundary of the map.
+///
+/// Distance to the boundary is found with a breadth-first search from tiles adjacent to the outside of the map, so
+/// it works for any map shape.
+pub(crate) fn find_edge_tiles(coords: &[Hex], edge_buffer: u8) -> Vec<bool>
 {
-    let side_length = map_dimension * 2 + 1;
-    let total_tiles = side_length * side_length;
+    let indices: HashMap<Hex, usize> = coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, i))
+        .collect();
 
-    // left
-    if i < edge_buffer * side_length {
-        return true;
+    let mut distances = vec![u32::MAX; coords.len()];
+    let mut queue = VecDeque::new();
+    for (i, coord) in coords.iter().enumerate() {
+        if coord
+            .all_neighbors()
+            .iter()
+            .any(|neighbor| !indices.contains_key(neighbor))
+        {
+            distances[i] = 0;
+            queue.push_back(i);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        let distance = distances[i] + 1;
+        for neighbor in coords[i].all_neighbors() {
+            let Some(neighbor) = indices.get(&neighbor).copied() else { continue };
+            if distances[neighbor] <= distance {
+                continue;
+            }
+            distances[neighbor] = distance;
+            queue.push_back(neighbor);
+        }
     }
 
-    // top
-    if i % side_length < edge_buffer {
-        return true;
+    distances
+        .into_iter()
+        .map(|distance| distance < edge_buffer as u32)
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chooses a tile type for each coordinate of the map.
+///
+/// Returns indices into `tiles`, or `None` for edge tiles.
+fn choose_map_tiles(
+    prng: &mut Rand64,
+    tiles: &[MapGenTile],
+    edge_tile: usize,
+    edges: &[bool],
+) -> Vec<Option<usize>>
+{
+    edges
+        .iter()
+        .map(|is_edge| {
+            // Always advance the prng so edge tiles don't affect other tiles.
+            let roll = prng.next() as f64 / u64::MAX as f64;
+            if *is_edge {
+                return None;
+            }
+
+            // Fall back to the edge tile type if something went wrong.
+            Some(
+                tiles
+                    .iter()
+                    .position(|tile| tile.rng_cutoff >= roll)
+                    .unwrap_or(edge_tile),
+            )
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Grows clusters around tiles whose specs have a cluster size.
+///
+/// Tiles chosen by [`choose_map_tiles`] act as cluster seeds. Each seed claims random adjacent tiles until its
+/// cluster reaches the target size or runs out of room. Seeds of other clusters and tiles already claimed by a
+/// cluster are never overwritten.
+fn grow_clusters(prng: &mut Rand64, tiles: &[MapGenTile], coords: &[Hex], chosen: &mut [Option<usize>])
+{
+    let indices: HashMap<Hex, usize> = coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, i))
+        .collect();
+    let cluster_size = |cell: Option<usize>| cell.map(|tile| tiles[tile].spec.cluster_size).unwrap_or(1);
+
+    let seeds: Vec<usize> = (0..chosen.len())
+        .filter(|cell| cluster_size(chosen[*cell]) > 1)
+        .collect();
+    let mut locked: Vec<bool> = chosen
+        .iter()
+        .map(|cell| cell.is_none() || cluster_size(*cell) > 1)
+        .collect();
+
+    let mut cluster = Vec::new();
+    let mut candidates = Vec::new();
+    for seed in seeds {
+        let size = cluster_size(chosen[seed]) as usize;
+        cluster.clear();
+        cluster.push(seed);
+        while cluster.len() < size {
+            candidates.clear();
+            candidates.extend(
+                cluster
+                    .iter()
+                    .flat_map(|cell| coords[*cell].all_neighbors())
+                    .filter_map(|neighbor| indices.get(&neighbor).copied())
+                    .filter(|neighbor| !locked[*neighbor]),
+            );
+            candidates.sort_unstable();
+            candidates.dedup();
+            if candidates.is_empty() {
+                break;
+            }
+
+            let claimed = candidates[(prng.next() % candidates.len() as u64) as usize];
+            chosen[claimed] = chosen[seed];
+            locked[claimed] = true;
+            cluster.push(claimed);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Smooths clustered terrain with a cellular automaton.
+///
+/// Each pass reads the tiles of the previous pass, so results don't depend on iteration order. A tile joins an
+/// adjacent cluster when at least [`CLUSTER_FILL_THRESHOLD`] of its neighbors belong to it, which fills gaps and
+/// rounds off jagged cluster edges. A clustered tile with no neighbors of its own type is replaced with its most
+/// common neighboring type. Edge tiles are never changed, and ties are broken by tile index so clients and the
+/// server produce identical maps.
+fn smooth_clusters(tiles: &[MapGenTile], coords: &[Hex], chosen: &mut [Option<usize>])
+{
+    let indices: HashMap<Hex, usize> = coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, i))
+        .collect();
+    let is_clustered = |tile: usize| tiles[tile].spec.cluster_size > 1;
+
+    let mut counts = vec![0usize; tiles.len()];
+    for _ in 0..CLUSTER_SMOOTHING_PASSES {
+        let prev = chosen.to_vec();
+        for (i, coord) in coords.iter().enumerate() {
+            let Some(current) = prev[i] else { continue };
+
+            counts.fill(0);
+            for neighbor in coord.all_neighbors() {
+                let Some(Some(tile)) = indices.get(&neighbor).map(|neighbor| prev[*neighbor]) else { continue };
+                counts[tile] += 1;
+            }
+
+            // Join the dominant adjacent cluster.
+            let fill = (0..tiles.len())
+                .filter(|tile| *tile != current && is_clustered(*tile))
+                .filter(|tile| counts[*tile] >= CLUSTER_FILL_THRESHOLD)
+                .max_by_key(|tile| (counts[*tile], Reverse(*tile)));
+            if let Some(fill) = fill {
+                chosen[i] = Some(fill);
+                continue;
+            }
+
+            // Remove stray clustered tiles.
+            if !is_clustered(current) || counts[current] > 0 {
+                continue;
+            }
+            if let Some(replacement) = (0..tiles.len())
+                .filter(|tile| counts[*tile] > 0)
+                .max_by_key(|tile| (counts[*tile], Reverse(*tile)))
+            {
+                chosen[i] = Some(replacement);
+            }
+        }
     }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces tiles outside a rough circular island with an ocean tile (see [`MapShape::Island`]).
+fn apply_island_mask(
+    prng: u64,
+    settings: &MapGenSettings,
+    roughness: u32,
+    ocean_tile: usize,
+    coords: &[Hex],
+    chosen: &mut [Option<usize>],
+)
+{
+    // Use a separate prng so the island shape doesn't affect the rest of the map.
+    let mut prng = Rand64::new("MAP GEN ISLAND PRNG", prng as u128);
+    let island_radius = (settings.map_dimension as u32).saturating_sub(settings.edge_buffer as u32 + roughness);
 
-    // bottom
-    if i % side_length > (side_length - 1 - edge_buffer) {
-        return true;
+    for (cell, coord) in chosen.iter_mut().zip(coords) {
+        let noise = (prng.next() % (roughness as u64 + 1)) as u32;
+        if cell.is_none() || coord.unsigned_distance_to(Hex::ZERO) + noise <= island_radius {
+            continue;
+        }
+        *cell = Some(ocean_tile);
     }
+}
 
-    // right
-    if i > total_tiles - edge_buffer * side_length {
-        return true;
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces tiles that are too close to other tiles of the same type (see [`TileSpec::min_same_tile_distance`]).
+///
+/// Tiles are checked in map order, so earlier tiles take precedence. Rejected tiles are resampled from the tile
+/// types that don't have spacing constraints.
+fn enforce_tile_spacing(prng: &mut Rand64, tiles: &[MapGenTile], coords: &[Hex], chosen: &mut [Option<usize>])
+{
+    if !tiles
+        .iter()
+        .any(|tile| tile.spec.min_same_tile_distance.is_some())
+    {
+        return;
     }
 
-    false
+    let unconstrained: Vec<(usize, f64)> = tiles
+        .iter()
+        .enumerate()
+        .filter(|(_, tile)| tile.spec.min_same_tile_distance.is_none())
+        .scan(0.0, |factor_sum, (idx, tile)| {
+            *factor_sum += tile.spec.mapgen_factor as f64;
+            Some((idx, *factor_sum))
+        })
+        .collect();
+    let Some((fallback, factor_total)) = unconstrained.last().copied() else {
+        tracing::error!("failed enforcing tile spacing, all tile types have spacing constraints");
+        return;
+    };
+
+    let mut placed: Vec<Vec<Hex>> = vec![Vec::new(); tiles.len()];
+    for (cell, coord) in chosen.iter_mut().zip(coords) {
+        let Some(tile) = *cell else { continue };
+        let Some(min_distance) = tiles[tile].spec.min_same_tile_distance else { continue };
+
+        if placed[tile]
+            .iter()
+            .any(|other| other.unsigned_distance_to(*coord) < min_distance)
+        {
+            let roll = prng.next() as f64 / u64::MAX as f64 * factor_total;
+            let resampled = unconstrained
+                .iter()
+                .find(|(_, cutoff)| *cutoff >= roll)
+                .map(|(idx, _)| *idx)
+                .unwrap_or(fallback);
+            *cell = Some(resampled);
+            continue;
+        }
+
+        placed[tile].push(*coord);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn try_spawn_map_tile(
+fn spawn_map_tile(
     c: &mut Commands,
-    i: usize,
-    coord: Hex,
     pos: Vec2,
-    prng: f64,
-    settings: &MapGenSettings,
-    tiles: &[MapGenTile],
-    edge_tile: &MapGenTile,
+    tile: &GeneratedTile,
+    spec: &TileSpec,
     ignore_edge_tiles: bool,
 ) -> Option<Entity>
 {
     let transform = Transform::from_xyz(pos.x, pos.y, 0.0);
+    let core = (MapTile(tile.coord), tile.id.clone(), transform);
 
-    if is_edge_tile(settings.map_dimension, settings.edge_buffer as i32, i as i32) {
+    if tile.is_edge {
         if ignore_edge_tiles {
             return None;
         } else {
-            let core = (MapTile(coord), edge_tile.id.clone(), transform);
             let ec = c.spawn((EdgeTile, core));
             return Some(ec.id());
         }
     }
 
-    for tile in tiles.iter() {
-        if tile.rng_cutoff < prng {
-            continue;
-        }
-        let core = (MapTile(coord), tile.id.clone(), transform);
-        let ec = match (tile.spec.is_ownable, tile.spec.is_water_tile) {
-            (true, true) => c.spawn((OwnableTile, WaterTile, core)),
-            (true, false) => c.spawn((OwnableTile, core)),
-            (false, true) => c.spawn((WaterTile, core)),
-            (false, false) => c.spawn(core),
-        };
-        return Some(ec.id());
-    }
-
-    // Fall back to edge tile if something went wrong.
-    Some(
-        c.spawn((MapTile(coord), edge_tile.id.clone(), transform))
-            .id(),
-    )
+    let ec = match (spec.is_ownable, spec.is_water_tile) {
+        (true, true) => c.spawn((OwnableTile, WaterTile, core)),
+        (true, false) => c.spawn((OwnableTile, core)),
+        (false, true) => c.spawn((WaterTile, core)),
+        (false, false) => c.spawn(core),
+    };
+    Some(ec.id())
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn generate_map(mut c: Commands, ctx: Res<GameContext>, settings: Res<MapGenSettings>, tile_data: Res<TileData>)
+pub(crate) fn generate_map(
+    mut c: Commands,
+    ctx: Res<GameContext>,
+    settings: Res<MapGenSettings>,
+    tile_data: Res<TileData>,
+)
 {
     // Ignores edge tiles since the game server doesn't use them.
     generate_map_impl(&mut c, map_gen_prng(ctx.seed), &settings, &tile_data, true);
@@ -103,6 +331,59 @@ fn generate_map(mut c: Commands, ctx: Res<GameContext>, settings: Res<MapGenSett
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Shape of generated maps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum MapShape
+{
+    /// Rectangle extending [`MapGenSettings::map_dimension`] tiles from the center in each direction.
+    #[default]
+    Rectangle,
+    /// Hexagon with radius [`MapGenSettings::map_dimension`].
+    Hexagon,
+    /// Parallelogram extending [`MapGenSettings::map_dimension`] tiles from the center along each axis.
+    Parallelogram,
+    /// Hexagon where tiles outside a rough circular island are replaced with `ocean_tile`.
+    ///
+    /// The coastline varies by up to `roughness` tiles.
+    Island
+    {
+        ocean_tile: TileId, roughness: u32
+    },
+}
+
+impl MapShape
+{
+    /// Gets the coordinates of all tiles in the shape, in map order.
+    pub fn coords(&self, map_dimension: i32) -> Vec<Hex>
+    {
+        let dim = map_dimension;
+        match self {
+            Self::Rectangle => hexx::shapes::flat_rectangle([-dim, dim, -dim, dim]).collect(),
+            Self::Hexagon | Self::Island { .. } => hexx::shapes::hexagon(Hex::ZERO, dim as u32).collect(),
+            Self::Parallelogram => hexx::shapes::parallelogram(Hex::new(-dim, -dim), Hex::new(dim, dim)).collect(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Strategy for laying out terrain when generating maps.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum TerrainMode
+{
+    /// Each tile is chosen independently based on tile mapgen factors.
+    #[default]
+    Random,
+    /// Tiles with a [`TileSpec::cluster_size`] grow into clusters (forests, lakes, mountain ranges, etc.).
+    ///
+    /// Cluster seeds are generated less often in proportion to their cluster size, so tile frequencies remain
+    /// roughly proportional to mapgen factors. Seeds grow randomly to their cluster size, then a few cellular
+    /// automaton passes smooth the result into contiguous regions with rounded edges.
+    Clustered,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Settings for generating maps.
 ///
 /// Loaded from config file.
@@ -110,10 +391,38 @@ fn generate_map(mut c: Commands, ctx: Res<GameContext>, settings: Res<MapGenSett
 pub struct MapGenSettings
 {
     pub hex_scale: Vec2,
-    /// Half-size of one side of the map square, in number of tiles.
+    /// Distance in tiles from the center of the map to its sides (see [`MapShape`]).
     pub map_dimension: i32,
     /// Number of tile layers on the edge of the map containing untouchable boundary tiles.
     pub edge_buffer: u8,
+    #[serde(default)]
+    pub map_shape: MapShape,
+    #[serde(default)]
+    pub terrain_mode: TerrainMode,
+}
+
+impl MapGenSettings
+{
+    pub(crate) fn validate(&self, tiles: &TileData) -> Result<(), String>
+    {
+        if self.map_dimension <= self.edge_buffer as i32 {
+            return Err("MapGenSettings map dimension must be larger than the edge buffer".into());
+        }
+        if let MapShape::Island { ocean_tile, .. } = &self.map_shape {
+            let Some(spec) = tiles.get(ocean_tile) else {
+                return Err(
+                    format!("MapGenSettings has island shape with unregistered ocean tile {:?}", ocean_tile),
+                );
+            };
+            if spec.is_ownable || spec.is_edge_tile {
+                return Err(
+                    format!("MapGenSettings has island shape with ownable or edge ocean tile {:?}", ocean_tile),
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -130,24 +439,39 @@ pub struct HexGrid
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Set `ignore_edge_tiles = true` if edge tile entities are not needed (e.g. the server).
-pub fn generate_map_impl(
-    c: &mut Commands,
-    prng: u64,
-    settings: &MapGenSettings,
-    tile_data: &TileData,
-    ignore_edge_tiles: bool,
-)
+/// A tile chosen during map generation.
+#[derive(Debug, Clone)]
+pub struct GeneratedTile
+{
+    pub coord: Hex,
+    pub id: TileId,
+    /// Edge tiles are untouchable boundary tiles on the edge of the map.
+    pub is_edge: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chooses the tiles of a map without spawning anything.
+///
+/// Tiles are returned in map order. The map is fully determined by `prng`, `settings`, and `tile_data`, so clients
+/// and the server generate identical maps.
+pub fn generate_map_tiles(prng_seed: u64, settings: &MapGenSettings, tile_data: &TileData) -> Vec<GeneratedTile>
 {
     // prep tile generator
-    let mut prng = Rand64::new("MAP GEN PRNG", prng as u128);
+    let mut prng = Rand64::new("MAP GEN PRNG", prng_seed as u128);
 
     // prep tile frequencies
+    // - In clustered mode, each seed becomes a whole cluster so seeds must be generated less often.
+    let is_clustered = matches!(settings.terrain_mode, TerrainMode::Clustered);
     let mut tiles: Vec<MapGenTile> = tile_data
         .iter()
-        .scan(0, |factor_sum, (id, spec)| {
-            *factor_sum += spec.mapgen_factor;
-            Some(MapGenTile { id: id.clone(), spec, rng_cutoff: *factor_sum as f64 })
+        .scan(0.0, |factor_sum, (id, spec)| {
+            let factor = match is_clustered {
+                true => spec.mapgen_factor as f64 / spec.cluster_size.max(1) as f64,
+                false => spec.mapgen_factor as f64,
+            };
+            *factor_sum += factor;
+            Some(MapGenTile { id: id.clone(), spec, rng_cutoff: *factor_sum })
         })
         .collect();
     let factor_total: f64 = tiles
@@ -160,37 +484,84 @@ pub fn generate_map_impl(
     });
     let edge_tile = tiles
         .iter()
-        .find(|tile| tile.spec.is_edge_tile)
+        .position(|tile| tile.spec.is_edge_tile)
         .expect("should be one edge tile type");
 
+    // choose tiles
+    let coords = settings.map_shape.coords(settings.map_dimension);
+    let edges = find_edge_tiles(&coords, settings.edge_buffer);
+    let mut chosen = choose_map_tiles(&mut prng, &tiles, edge_tile, &edges);
+    if is_clustered {
+        grow_clusters(&mut prng, &tiles, &coords, &mut chosen);
+        smooth_clusters(&tiles, &coords, &mut chosen);
+    }
+    if let MapShape::Island { ocean_tile, roughness } = &settings.map_shape {
+        match tiles.iter().position(|tile| tile.id == *ocean_tile) {
+            Some(ocean_tile) => {
+                apply_island_mask(prng_seed, settings, *roughness, ocean_tile, &coords, &mut chosen);
+            }
+            None => tracing::error!(?ocean_tile, "island ocean tile is missing from tile data"),
+        }
+    }
+    enforce_tile_spacing(&mut prng, &tiles, &coords, &mut chosen);
+
+    coords
+        .into_iter()
+        .zip(chosen)
+        .map(|(coord, cell)| GeneratedTile {
+            coord,
+            id: tiles[cell.unwrap_or(edge_tile)].id.clone(),
+            is_edge: cell.is_none(),
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Generates a map and spawns its tiles.
+///
+/// Set `ignore_edge_tiles = true` if edge tile entities are not needed (e.g. the server).
+pub fn generate_map_impl(
+    c: &mut Commands,
+    prng: u64,
+    settings: &MapGenSettings,
+    tile_data: &TileData,
+    ignore_edge_tiles: bool,
+)
+{
+    let tiles = generate_map_tiles(prng, settings, tile_data);
+
     // spawn the hex grid with sprites assigned to each hex
     let layout = HexLayout {
         orientation: HexOrientation::Flat,
         scale: settings.hex_scale,
         ..default()
     };
-    let map_dim = settings.map_dimension;
-    let dim = map_dim * 2 + 1;
-    let map_size = (dim * dim) as usize;
 
     let mut tile_map = HashMap::default();
     let mut entities = HashMap::default();
-    tile_map.reserve(map_size);
-    entities.reserve(map_size);
-
-    for (i, coord) in hexx::shapes::flat_rectangle([-map_dim, map_dim, -map_dim, map_dim]).enumerate() {
-        let pos = layout.hex_to_world_pos(coord);
-        let prng = prng.next() as f64 / u64::MAX as f64;
-        let Some(entity) =
-            try_spawn_map_tile(c, i, coord, pos, prng, settings, &tiles, edge_tile, ignore_edge_tiles)
-        else {
+    tile_map.reserve(tiles.len());
+    entities.reserve(tiles.len());
+
+    for tile in tiles.iter() {
+        let Some(spec) = tile_data.get(&tile.id) else {
+            tracing::error!(?tile, "generated tile is missing from tile data");
+            continue;
+        };
+        let pos = layout.hex_to_world_pos(tile.coord);
+        let Some(entity) = spawn_map_tile(c, pos, tile, spec, ignore_edge_tiles) else {
             continue;
         };
 
-        tile_map.insert(coord, entity);
-        entities.insert(entity, coord);
+        tile_map.insert(tile.coord, entity);
+        entities.insert(entity, tile.coord);
     }
-    c.insert_resource(HexGrid { tiles: tile_map, entities, layout, dimension: map_dim });
+    c.insert_resource(HexGrid {
+        tiles: tile_map,
+        entities,
+        layout,
+        dimension: settings.map_dimension,
+    });
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -207,3 +578,62 @@ impl Plugin for MapGenPlugin
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use std::path::PathBuf;
+
+    use utils::RootConfigs;
+
+    use super::*;
+
+    fn test_game_data() -> GameData
+    {
+        let config_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../config");
+        let configs = RootConfigs::new(&config_dir, &["game"]).unwrap();
+        GameData::new(&configs).unwrap()
+    }
+
+    #[test]
+    fn spaced_tiles_respect_min_distance()
+    {
+        let data = test_game_data();
+        let spaced: Vec<(TileId, u32)> = data
+            .tiles
+            .iter()
+            .filter_map(|(id, spec)| {
+                spec.min_same_tile_distance
+                    .map(|distance| (id.clone(), distance))
+            })
+            .collect();
+        assert!(!spaced.is_empty(), "config should have a tile with a min same-tile distance");
+
+        let mut checked_pairs = 0;
+        for terrain_mode in [TerrainMode::Random, TerrainMode::Clustered] {
+            let settings = MapGenSettings { terrain_mode, ..data.mapgen_settings.clone() };
+            for seed in 0..4 {
+                let tiles = generate_map_tiles(map_gen_prng(seed), &settings, &data.tiles);
+                for (id, min_distance) in spaced.iter() {
+                    let positions: Vec<Hex> = tiles
+                        .iter()
+                        .filter(|tile| tile.id == *id)
+                        .map(|tile| tile.coord)
+                        .collect();
+                    for (i, a) in positions.iter().enumerate() {
+                        for b in positions[i + 1..].iter() {
+                            assert!(
+                                a.unsigned_distance_to(*b) >= *min_distance,
+                                "seed {seed} placed {id:?} at {a:?} and {b:?}, closer than {min_distance}"
+                            );
+                            checked_pairs += 1;
+                        }
+                    }
+                }
+            }
+        }
+        assert!(checked_pairs > 0, "generated maps should contain spaced tiles to check");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -23,7 +23,7 @@ pub struct MapTile(pub Hex);
 #[component(immutable)]
 pub struct EdgeTile;
 
-/// Marker component for tiles that have water proximity effects.
+/// Marker component for tiles that have water proximity effects (see [`ProximityModifier::Water`]).
 #[derive(Component, Debug, Copy, Clone)]
 #[component(immutable)]
 pub struct WaterTile;
@@ -68,10 +68,10 @@ fn add_parent_tile(mut w: DeferredWorld, context: HookContext)
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Marker component for tile metas of tiles that can be selected by each player during tile select.
-//TODO: on spawn, must include vis!(Client(client_id))
-//TODO: when a tile is selected, despawn the SelectedTile entity and spawn a TileOwner entity; when deselected,
-//despawn the TileOwner and spawn a SelectedTile
+/// Component for tile metas of tiles that can be selected by a player during tile select.
+///
+/// Spawned near each player's HQ and only visible to that player and watchers. Selecting the tile inserts a
+/// [`TileOwner`] alongside this component. Removed when tile select ends.
 #[derive(Component, Debug, Copy, Clone, Serialize, Deserialize)]
 #[component(immutable)]
 pub struct SelectableTile
@@ -93,24 +93,24 @@ pub struct TileClaims
 
 impl TileClaims
 {
-    pub(crate) fn _new(client: u64) -> Self
+    pub(crate) fn new(client: u64) -> Self
     {
         let claimants = HashSet::from_iter([client]);
         Self { claimants, age: 0 }
     }
 
-    pub(crate) fn _add_claimant(&mut self, client: u64) -> bool
+    pub(crate) fn add_claimant(&mut self, client: u64) -> bool
     {
         self.claimants.insert(client)
     }
 
-    pub(crate) fn _remove_claimant(&mut self, client: u64) -> bool
+    pub(crate) fn remove_claimant(&mut self, client: u64) -> bool
     {
         self.claimants.remove(&client)
     }
 
-    /// Advances the claiming age and returns the previous rounds' claimants.
-    pub(crate) fn _next_round(&mut self) -> HashSet<u64>
+    /// Advances the claiming age and returns the previous round's claimants.
+    pub(crate) fn next_round(&mut self) -> HashSet<u64>
     {
         self.age += 1;
         std::mem::take(&mut self.claimants)
@@ -9,19 +9,7 @@ use crate::*;
 /// Configuration details for game duration.
 /*
 TODO:
-server
-- send time remaining in current phase every 5 seconds in reliable unordered messages
-    - also on client connect
-    - also on phase change
-- store current round as resource
-    - send current round as message
-        - on phase change
-        - on client connect
-        - TODO: replicate this resource
-
-client
-- store phase timer as resource
-- update phase timer when phase timer message received
+- replicate the current round instead of sending it in messages
 */
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct GameDurationConfig
@@ -16,6 +16,14 @@ pub struct ProvGameInitializer
     pub game_context: GameContext,
     /// Player states.
     pub players: HashMap<ClientId, PlayerState>,
+    /// Players controlled by the server.
+    pub bots: HashMap<ClientId, BotDifficulty>,
+    /// Server-side user ids of human players, saved in snapshots so users can reconnect to resumed games.
+    pub user_ids: HashMap<ClientId, u128>,
+    /// Snapshot to resume the game from.
+    ///
+    /// Players must match the players in the snapshot.
+    pub snapshot: Option<GameSnapshot>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
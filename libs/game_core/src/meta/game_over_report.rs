@@ -5,7 +5,7 @@ use crate::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Player report for the game over report.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProvPlayerReport
 {
     /// Client id within the game.
@@ -15,7 +15,7 @@ pub struct ProvPlayerReport
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Report emitted at the end of a game.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProvGameOverReport
 {
     /// Id of the game used for this report.
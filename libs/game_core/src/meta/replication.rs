@@ -16,10 +16,15 @@ impl Plugin for GameReplicationPlugin
     {
         app.replicate::<PlayerId>()
             .replicate::<PlayerName>()
+            .replicate::<PlayerInventory>()
+            .replicate::<PlayerSight>()
+            .replicate::<QueuedOrders>()
             .replicate::<TileMeta>()
             .replicate::<SelectableTile>()
             .replicate::<TileClaims>()
-            .replicate::<TileOwner>();
+            .replicate::<TileOwner>()
+            .replicate::<BuildingId>()
+            .replicate::<BuildingCondition>();
     }
 }
 
@@ -19,7 +19,19 @@ impl Plugin for GamePlugin
             .add_plugins(GameSetupPlugin)
             .add_plugins(GameStatePlugin)
             .add_plugins(GameRoundPlugin)
+            .add_plugins(StartRegionsPlugin)
+            .add_plugins(TileSelectPlugin)
+            .add_plugins(TileClaimsPlugin)
+            .add_plugins(RoundResolutionPlugin)
+            .add_plugins(ProductionPlugin)
+            .add_plugins(TransportPlugin)
+            .add_plugins(FogOfWarPlugin)
+            .add_plugins(BotPlugin)
+            .add_plugins(ReplayPlugin)
+            .add_plugins(SnapshotPlugin)
             .add_plugins(ClientConnectPlugin)
+            .add_plugins(ClockSyncPlugin)
+            .add_plugins(WatcherPlugin)
             .add_plugins(MapPlugin)
             .configure_sets(
                 Update,
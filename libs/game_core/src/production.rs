@@ -0,0 +1,94 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Credits resources produced by production buildings to their owners at the end of a round.
+///
+/// Buildings that require services only produce in proportion to the services allocated to them this round.
+/// Buildings that aren't connected to their owner's HQ by the transport network have reduced production. Players
+/// without an HQ don't have a transport network, so their buildings aren't penalized.
+/// Proximity modifiers are applied based on each building's neighboring tiles.
+///
+/// Each player that produced anything is sent a summary of their production for the round.
+pub(crate) fn produce_resources(
+    round: Res<GameRound>,
+    buildings: Res<BuildingData>,
+    transport: Res<TransportSettings>,
+    grid: Res<HexGrid>,
+    water: Query<(), With<WaterTile>>,
+    producers: Query<(Entity, &TileMeta, &TileOwner, &BuildingId)>,
+    transports: Query<(&TileMeta, &TileOwner, &BuildingId, Option<&BuildingCondition>)>,
+    mut inventories: Query<&mut PlayerInventory>,
+    mut sender: GameSender,
+)
+{
+    let connected = connected_tiles(&buildings, transports.iter());
+    let allocation = allocate_services(&buildings, &transport, &connected, producers.iter());
+    let mut production = HashMap::<ClientId, HashMap<ResourceId, u64>>::default();
+
+    for (entity, meta, owner, building_id) in producers.iter() {
+        let Some(spec) = buildings.get(building_id) else {
+            tracing::error!(?meta, "building {building_id:?} is missing from building data");
+            continue;
+        };
+        let BuildingTypeSpec::Production { resource_id, production_per_round } = &spec.building_type else {
+            continue;
+        };
+        let mut fraction = allocation.get(&entity).copied().unwrap_or(1.0);
+        if connected
+            .get(&owner.0)
+            .is_some_and(|tiles| !tiles.contains(&meta.tile))
+        {
+            fraction *= transport.unconnected_production_factor as f64;
+        }
+        let water_neighbors = count_water_neighbors(meta.tile, &grid, &water);
+        fraction *= (100 + production_modifier_percent(spec, water_neighbors)) as f64 / 100.0;
+        let amount = (*production_per_round as f64 * fraction).floor() as u64;
+        if amount == 0 {
+            continue;
+        }
+
+        let produced = production
+            .entry(owner.0)
+            .or_default()
+            .entry(resource_id.clone())
+            .or_default();
+        *produced = produced.saturating_add(amount);
+    }
+
+    for mut inventory in inventories.iter_mut() {
+        let owner = inventory.owner();
+        let mut produced: Vec<(ResourceId, u64)> = production
+            .remove(&owner)
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        produced.sort_unstable_by(|(a, _), (b, _)| a.get().cmp(b.get()));
+
+        for (resource_id, amount) in produced.iter() {
+            inventory.add_resource(resource_id, *amount);
+        }
+
+        if produced.is_empty() {
+            continue;
+        }
+        sender.send_to_client(GameMsg::ProductionSummary { round: **round, produced }, owner);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ProductionPlugin;
+
+impl Plugin for ProductionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(RoundEnd, produce_resources);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
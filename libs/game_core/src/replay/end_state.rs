@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A player's inventory at the end of a game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerEndState
+{
+    pub client_id: ClientId,
+    pub gold: u64,
+    /// Sorted by resource id.
+    pub resources: Vec<(ResourceId, u64)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tile meta entity at the end of a game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TileEndState
+{
+    pub tile: Hex,
+    pub owner: Option<ClientId>,
+    pub building: Option<BuildingId>,
+    /// Sorted claimants of the tile, if it has [`TileClaims`].
+    pub claimants: Vec<ClientId>,
+    pub claim_age: Option<u16>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Deterministic summary of the game state at the end of a game.
+///
+/// Used by replays to check that playback reproduces the recorded game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameEndState
+{
+    /// Sorted by client id.
+    pub players: Vec<PlayerEndState>,
+    /// Sorted by tile coordinate.
+    pub tiles: Vec<TileEndState>,
+}
+
+impl GameEndState
+{
+    /// Collects the end state from a game world.
+    pub fn from_world(world: &mut World) -> Self
+    {
+        let mut players: Vec<PlayerEndState> = world
+            .query::<&PlayerInventory>()
+            .iter(world)
+            .map(|inventory| {
+                let mut resources: Vec<(ResourceId, u64)> = inventory
+                    .resources()
+                    .iter()
+                    .map(|(id, amount)| (id.clone(), *amount))
+                    .collect();
+                resources.sort_unstable_by(|(a, _), (b, _)| a.get().cmp(b.get()));
+                PlayerEndState {
+                    client_id: inventory.owner(),
+                    gold: inventory.gold(),
+                    resources,
+                }
+            })
+            .collect();
+        players.sort_unstable_by_key(|player| player.client_id);
+
+        let mut tiles: Vec<TileEndState> = world
+            .query::<(&TileMeta, Option<&TileOwner>, Option<&BuildingId>, Option<&TileClaims>)>()
+            .iter(world)
+            .map(|(meta, owner, building, claims)| {
+                let mut claimants: Vec<ClientId> = claims
+                    .map(|claims| claims.claimants().iter().copied().collect())
+                    .unwrap_or_default();
+                claimants.sort_unstable();
+                TileEndState {
+                    tile: meta.tile,
+                    owner: owner.map(|owner| owner.0),
+                    building: building.cloned(),
+                    claimants,
+                    claim_age: claims.map(|claims| claims.age()),
+                }
+            })
+            .collect();
+        tiles.sort_unstable_by_key(|tile| (tile.tile.x, tile.tile.y));
+
+        Self { players, tiles }
+    }
+
+    /// Describes the first difference between two end states, or returns `None` if they match.
+    pub fn diff(&self, other: &Self) -> Option<String>
+    {
+        if self.players.len() != other.players.len() {
+            return Some(format!("player count {} vs {}", self.players.len(), other.players.len()));
+        }
+        if let Some((a, b)) = self
+            .players
+            .iter()
+            .zip(other.players.iter())
+            .find(|(a, b)| a != b)
+        {
+            return Some(format!("player {a:?} vs {b:?}"));
+        }
+        if self.tiles.len() != other.tiles.len() {
+            return Some(format!("tile meta count {} vs {}", self.tiles.len(), other.tiles.len()));
+        }
+        if let Some((a, b)) = self
+            .tiles
+            .iter()
+            .zip(other.tiles.iter())
+            .find(|(a, b)| a != b)
+        {
+            return Some(format!("tile {a:?} vs {b:?}"));
+        }
+
+        None
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,7 @@
+mod end_state;
+mod recording;
+mod replay_file;
+
+pub use end_state::*;
+pub use recording::*;
+pub use replay_file::*;
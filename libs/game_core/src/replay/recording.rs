@@ -0,0 +1,179 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for recording replays.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct ReplaySettings
+{
+    /// If enabled, a replay file is written when the game ends.
+    pub enabled: bool,
+    /// Directory where replay files are written.
+    pub directory: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the information needed to make a [`Replay`].
+///
+/// Inserted with the game data if replays are enabled.
+#[derive(Resource, Debug)]
+pub struct ReplayRecorder
+{
+    game_data: GameData,
+    game_id: u64,
+    seed: u128,
+    duration_config: Option<GameDurationConfig>,
+    players: Vec<ReplayPlayer>,
+    requests: Vec<ReplayRequest>,
+    used_commands: bool,
+    game_over_report: Option<ProvGameOverReport>,
+    end_state: Option<GameEndState>,
+}
+
+impl ReplayRecorder
+{
+    pub(crate) fn new(game_data: GameData) -> Self
+    {
+        Self {
+            game_data,
+            game_id: 0,
+            seed: 0,
+            duration_config: None,
+            players: Vec::default(),
+            requests: Vec::default(),
+            used_commands: false,
+            game_over_report: None,
+            end_state: None,
+        }
+    }
+
+    pub(crate) fn set_context(&mut self, context: &GameContext, mut players: Vec<ReplayPlayer>)
+    {
+        players.sort_unstable_by_key(|player| player.client_id);
+        self.game_id = context.game_id;
+        self.seed = context.seed;
+        self.duration_config = Some(context.duration_config);
+        self.players = players;
+    }
+
+    pub(crate) fn record_request(&mut self, time: Duration, client_id: ClientId, request: ClientRequest)
+    {
+        self.requests
+            .push(ReplayRequest { time, client_id, request });
+    }
+
+    pub(crate) fn record_command(&mut self)
+    {
+        self.used_commands = true;
+    }
+
+    pub(crate) fn set_game_over_report(&mut self, report: ProvGameOverReport)
+    {
+        self.game_over_report = Some(report);
+    }
+
+    /// Makes a replay. Returns `None` if the game hasn't ended.
+    pub fn build(&self) -> Result<Option<Replay>, String>
+    {
+        let Some(game_over_report) = self.game_over_report.clone() else { return Ok(None) };
+        let Some(duration_config) = self.duration_config else {
+            return Err("replay recorder is missing the game context".into());
+        };
+        let Some(end_state) = self.end_state.clone() else {
+            return Err("replay recorder is missing the game end state".into());
+        };
+
+        Ok(Some(Replay {
+            version: REPLAY_VERSION,
+            game_id: self.game_id,
+            seed: self.seed,
+            duration_config,
+            config_hash: config_hash(&self.game_data)?,
+            game_data: self.game_data.clone(),
+            players: self.players.clone(),
+            requests: self.requests.clone(),
+            used_commands: self.used_commands,
+            game_over_report,
+            end_state,
+        }))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the game end state so playback can be checked against it.
+fn record_end_state(world: &mut World)
+{
+    let end_state = GameEndState::from_world(world);
+    let Some(mut recorder) = world.get_resource_mut::<ReplayRecorder>() else { return };
+    recorder.end_state = Some(end_state);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes a replay file once the game over report is available.
+fn save_replay(settings: Res<ReplaySettings>, recorder: Option<Res<ReplayRecorder>>)
+{
+    let Some(recorder) = recorder else { return };
+    let replay = match recorder.build() {
+        Ok(Some(replay)) => replay,
+        Ok(None) => {
+            tracing::error!("failed saving replay; game over report is missing");
+            return;
+        }
+        Err(err) => {
+            tracing::error!("failed building replay: {err}");
+            return;
+        }
+    };
+
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let json = match replay.to_json() {
+            Ok(json) => json,
+            Err(err) => {
+                tracing::error!("failed saving replay: {err}");
+                return;
+            }
+        };
+        let dir = std::path::Path::new(&settings.directory);
+        let path = dir.join(format!("replay-{}-{:x}.json", replay.game_id, replay.seed));
+        if let Err(err) = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&path, json)) {
+            tracing::error!(?path, "failed writing replay file: {err:?}");
+            return;
+        }
+        tracing::info!(?path, "saved replay");
+    }
+
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = (settings, replay);
+        tracing::warn!("replay files are not supported on WASM");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            OnEnter(GameState::End),
+            (record_end_state, save_replay)
+                .chain()
+                .after(set_game_end_flag)
+                .run_if(|settings: Res<ReplaySettings>| settings.enabled),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
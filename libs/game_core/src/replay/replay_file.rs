@@ -0,0 +1,171 @@
+use std::hash::Hasher;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Version of the replay file format. Increment this when the format or replay semantics change.
+pub const REPLAY_VERSION: u32 = 2;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Simple FNV-1a hasher, used so config hashes are stable across builds and platforms.
+struct FnvHasher(u64);
+
+impl Hasher for FnvHasher
+{
+    fn finish(&self) -> u64
+    {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8])
+    {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Hashes game data so replays can detect config mismatches.
+///
+/// Data is converted to a JSON value first so map keys are hashed in sorted order.
+pub fn config_hash(game_data: &GameData) -> Result<u64, String>
+{
+    let value = serde_json::to_value(game_data).map_err(|err| format!("failed serializing game data: {err:?}"))?;
+    let mut hasher = FnvHasher(0xcbf29ce484222325);
+    hasher.write(value.to_string().as_bytes());
+    Ok(hasher.finish())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A player in a replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayPlayer
+{
+    pub client_id: ClientId,
+    pub name: String,
+    /// Set if the player is a bot. Bot inputs aren't recorded since bots are re-run during playback.
+    pub bot: Option<BotDifficulty>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An accepted client request in a replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayRequest
+{
+    /// Game time when the request was handled.
+    pub time: Duration,
+    pub client_id: ClientId,
+    pub request: ClientRequest,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Everything needed to re-run a game deterministically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay
+{
+    pub version: u32,
+    pub game_id: u64,
+    pub seed: u128,
+    pub duration_config: GameDurationConfig,
+    /// Hash of `game_data`. See [`config_hash`].
+    pub config_hash: u64,
+    pub game_data: GameData,
+    pub players: Vec<ReplayPlayer>,
+    pub requests: Vec<ReplayRequest>,
+    /// Set if any command inputs were applied. Commands alter game time, so these replays may not reproduce.
+    pub used_commands: bool,
+    pub game_over_report: ProvGameOverReport,
+    /// Player inventories and tile metas when the game ended. Playback must reproduce these exactly.
+    pub end_state: GameEndState,
+}
+
+impl Replay
+{
+    /// Checks that the replay can be played back.
+    pub fn validate(&self) -> Result<(), String>
+    {
+        if self.version != REPLAY_VERSION {
+            return Err(format!(
+                "replay has version {} but only version {REPLAY_VERSION} is supported",
+                self.version
+            ));
+        }
+        let hash = config_hash(&self.game_data)?;
+        if hash != self.config_hash {
+            return Err(format!(
+                "replay config hash {} does not match its game data (hash {hash})",
+                self.config_hash
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Makes an initializer for re-running the replay's game.
+    pub fn initializer(&self) -> ProvGameInitializer
+    {
+        let mut players = std::collections::HashMap::default();
+        let mut bots = std::collections::HashMap::default();
+        for player in self.players.iter() {
+            players.insert(
+                player.client_id,
+                PlayerState {
+                    id: PlayerId { id: player.client_id },
+                    name: PlayerName { name: player.name.clone() },
+                    ..Default::default()
+                },
+            );
+            if let Some(difficulty) = player.bot {
+                bots.insert(player.client_id, difficulty);
+            }
+        }
+
+        ProvGameInitializer {
+            game_context: GameContext {
+                game_id: self.game_id,
+                seed: self.seed,
+                duration_config: self.duration_config,
+            },
+            players,
+            bots,
+            user_ids: std::collections::HashMap::default(),
+            snapshot: None,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String>
+    {
+        serde_json::to_string(self).map_err(|err| format!("failed serializing replay: {err:?}"))
+    }
+
+    /// Deserializes a replay, checking the version first so old replays get a clear error.
+    pub fn from_json(json: &str) -> Result<Self, String>
+    {
+        #[derive(Deserialize)]
+        struct ReplayVersion
+        {
+            version: u32,
+        }
+
+        let ReplayVersion { version } =
+            serde_json::from_str(json).map_err(|err| format!("failed parsing replay version: {err:?}"))?;
+        if version != REPLAY_VERSION {
+            return Err(format!("replay has version {version} but only version {REPLAY_VERSION} is supported"));
+        }
+
+        serde_json::from_str(json).map_err(|err| format!("failed deserializing replay: {err:?}"))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
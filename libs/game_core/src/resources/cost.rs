@@ -1,13 +1,13 @@
 use bevy::platform::collections::HashMap;
 use bevy::prelude::Deref;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::ResourceId;
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Note: does not include gold, which cannot be spent to construct things.
-#[derive(Deserialize, Debug, Clone, Deref)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Deref)]
 pub struct ResourceCost(pub HashMap<ResourceId, u64>);
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -2,12 +2,12 @@ use std::borrow::{Borrow, Cow};
 
 use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component with the canonical ID of a type of resource.
-#[derive(Component, Debug, Deserialize, Clone, Eq, PartialEq, Hash, Reflect)]
+#[derive(Component, Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Hash, Deserialize)]
 #[component(immutable)]
 pub struct ResourceId(Cow<'static, str>);
@@ -37,7 +37,7 @@ impl Borrow<str> for ResourceId
 
 /// Note: Does not include gold, which is a special asset that is not treated as a resource that can be collected
 /// and consumed.
-#[derive(Resource, Debug, Deserialize, Clone, Deref)]
+#[derive(Resource, Debug, Serialize, Deserialize, Clone, Deref)]
 pub struct ResourceData(HashSet<ResourceId>);
 
 impl ResourceData
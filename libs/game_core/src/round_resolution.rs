@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::vis::*;
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How player actions are applied during rounds.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RoundResolutionMode
+{
+    /// Actions are applied as soon as they are received.
+    #[default]
+    Immediate,
+    /// Actions are queued as [`QueuedOrders`] and applied together at the end of the round.
+    Queued,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for resolving player actions during rounds.
+#[derive(Resource, Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct RoundResolutionSettings
+{
+    pub mode: RoundResolutionMode,
+    /// Maximum number of orders a player can queue each round when rounds use [`RoundResolutionMode::Queued`].
+    pub max_orders_per_player: usize,
+}
+
+impl RoundResolutionSettings
+{
+    pub fn is_queued(&self) -> bool
+    {
+        self.mode == RoundResolutionMode::Queued
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String>
+    {
+        if self.max_orders_per_player == 0 {
+            return Err("RoundResolutionSettings max orders per player is zero".into());
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The outcome of a queued order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderResult
+{
+    pub order: PlayerInput,
+    pub result: Result<(), RejectionReason>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rejects queued orders that can't succeed no matter what happens earlier in the round.
+///
+/// Orders are fully validated when they are applied at the end of the round.
+fn check_queued_order(
+    client_id: ClientId,
+    order: &PlayerInput,
+    grid: &HexGrid,
+    tiles: &Query<(Has<OwnableTile>, Option<&AttachedMeta>), With<MapTile>>,
+    owners: &Query<&TileOwner, With<TileMeta>>,
+) -> Result<(), RejectionReason>
+{
+    let tile = order.tile();
+    let Some((ownable, attached)) = grid
+        .tiles
+        .get(&tile)
+        .and_then(|tile_entity| tiles.get(*tile_entity).ok())
+    else {
+        tracing::debug!(?tile, "client {client_id} tried to queue an order for a tile that doesn't exist");
+        return Err(RejectionReason::InvalidTile);
+    };
+    let owner = attached
+        .and_then(|attached| owners.get(**attached).ok())
+        .map(|owner| owner.0);
+
+    match order {
+        PlayerInput::ClaimTile(_) if !ownable => {
+            tracing::debug!(?tile, "client {client_id} tried to queue a claim on an unownable tile");
+            Err(RejectionReason::InvalidTile)
+        }
+        PlayerInput::ClaimTile(_) if owner.is_some() => {
+            tracing::debug!(?tile, "client {client_id} tried to queue a claim on an owned tile");
+            Err(RejectionReason::TileAlreadyOwned)
+        }
+        PlayerInput::Build { .. } | PlayerInput::Upgrade { .. } | PlayerInput::Demolish { .. }
+            if owner != Some(client_id) =>
+        {
+            tracing::debug!(?tile, "client {client_id} tried to queue a building order on a tile it doesn't own");
+            Err(RejectionReason::TileNotOwned)
+        }
+        _ => Ok(()),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Queues a play action for the end of the round.
+pub(crate) fn handle_queue_order(
+    In((_player_entity, client_id, order)): In<(Entity, ClientId, PlayerInput)>,
+    settings: Res<RoundResolutionSettings>,
+    grid: Res<HexGrid>,
+    tiles: Query<(Has<OwnableTile>, Option<&AttachedMeta>), With<MapTile>>,
+    owners: Query<&TileOwner, With<TileMeta>>,
+    mut orders: Query<&mut QueuedOrders>,
+) -> Result<(), RejectionReason>
+{
+    check_queued_order(client_id, &order, &grid, &tiles, &owners)?;
+
+    let Some(mut orders) = orders.iter_mut().find(|orders| orders.owner() == client_id) else {
+        tracing::error!("client {client_id} is missing queued orders");
+        return Err(RejectionReason::Invalid);
+    };
+    // Replacing the order for a tile doesn't count against the limit.
+    if orders.get(order.tile()).is_none() && orders.orders().len() >= settings.max_orders_per_player {
+        tracing::debug!("client {client_id} tried to queue more than {} orders", settings.max_orders_per_player);
+        return Err(RejectionReason::TooManyOrders);
+    }
+    orders.queue(order);
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Cancels the queued order for a tile.
+pub(crate) fn handle_cancel_order(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    mut orders: Query<&mut QueuedOrders>,
+) -> Result<(), RejectionReason>
+{
+    let Some(mut orders) = orders.iter_mut().find(|orders| orders.owner() == client_id) else {
+        tracing::debug!(?tile, "client {client_id} tried to cancel an order, but orders aren't queued");
+        return Err(RejectionReason::NoQueuedOrder);
+    };
+    if !orders.cancel(tile) {
+        tracing::debug!(?tile, "client {client_id} tried to cancel an order that doesn't exist");
+        return Err(RejectionReason::NoQueuedOrder);
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns an empty [`QueuedOrders`] for each player if rounds use [`RoundResolutionMode::Queued`].
+pub(crate) fn spawn_queued_orders(world: &mut World, players: impl IntoIterator<Item = ClientId>)
+{
+    if !world
+        .get_resource::<RoundResolutionSettings>()
+        .is_some_and(|settings| settings.is_queued())
+    {
+        return;
+    }
+
+    for client_id in players {
+        world.spawn((QueuedOrders::new(client_id), vis!(Client(client_id))));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn notify_order_results(
+    In((client_id, round, results)): In<(ClientId, u32, Vec<OrderResult>)>,
+    mut sender: GameSender,
+)
+{
+    sender.send_to_client(GameMsg::OrderResults { round, results }, client_id);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shuffles players from a canonical order, so the result only depends on the RNG state.
+fn shuffle_players<T>(queues: &mut [(ClientId, T)], rand: &mut GameRand)
+{
+    queues.sort_unstable_by_key(|(client_id, _)| *client_id);
+    for idx in (1..queues.len()).rev() {
+        let swap_idx = (rand.next() % (idx as u64 + 1)) as usize;
+        queues.swap(idx, swap_idx);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies queued orders at the end of a round.
+///
+/// Players take turns applying one order at a time. The player order is shuffled each round with [`GameRand`], so
+/// resolution is deterministic for a given seed but no player is always first. Each human player is sent a report
+/// with the outcome of their orders.
+pub(crate) fn resolve_queued_orders(world: &mut World)
+{
+    let mut queues: Vec<(ClientId, Vec<PlayerInput>)> = world
+        .query::<&mut QueuedOrders>()
+        .iter_mut(world)
+        .map(|mut orders| (orders.owner(), orders.take()))
+        .filter(|(_, orders)| !orders.is_empty())
+        .collect();
+    if queues.is_empty() {
+        return;
+    }
+
+    shuffle_players(&mut queues, &mut world.resource_mut::<GameRand>());
+
+    let mut results = HashMap::<ClientId, Vec<OrderResult>>::default();
+    let max_orders = queues
+        .iter()
+        .map(|(_, orders)| orders.len())
+        .max()
+        .unwrap_or_default();
+    for order_idx in 0..max_orders {
+        for (client_id, orders) in queues.iter() {
+            let Some(order) = orders.get(order_idx).cloned() else { continue };
+            let Ok(player_entity) = world.resource::<PlayerMap>().client_to_entity(*client_id) else {
+                tracing::error!("client {client_id} with queued orders is not a player");
+                continue;
+            };
+
+            let result = apply_play_input(world, player_entity, *client_id, order.clone());
+            if let Err(reason) = &result {
+                tracing::debug!(?reason, "queued order {order:?} from client {client_id} failed");
+            }
+            results
+                .entry(*client_id)
+                .or_default()
+                .push(OrderResult { order, result });
+        }
+    }
+
+    let round = **world.resource::<GameRound>();
+    let mut results: Vec<(ClientId, Vec<OrderResult>)> = results.into_iter().collect();
+    results.sort_unstable_by_key(|(client_id, _)| *client_id);
+    for (client_id, results) in results {
+        // Bots aren't connected, so they can't be notified.
+        let Ok(player_entity) = world.resource::<PlayerMap>().client_to_entity(client_id) else { continue };
+        if world.get::<Bot>(player_entity).is_some() {
+            continue;
+        }
+        world.syscall((client_id, round, results), notify_order_results);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct RoundResolutionPlugin;
+
+impl Plugin for RoundResolutionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            RoundEnd,
+            resolve_queued_orders
+                .before(resolve_tile_claims)
+                .before(produce_resources)
+                .run_if(|settings: Res<RoundResolutionSettings>| settings.is_queued()),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn shuffled(client_ids: &[ClientId], seed: u128) -> Vec<ClientId>
+    {
+        let mut queues: Vec<(ClientId, ())> = client_ids
+            .iter()
+            .map(|client_id| (*client_id, ()))
+            .collect();
+        shuffle_players(&mut queues, &mut GameRand::new(seed));
+        queues.into_iter().map(|(client_id, _)| client_id).collect()
+    }
+
+    #[test]
+    fn shuffle_is_deterministic_for_seed()
+    {
+        let players = [1, 2, 3, 4, 5, 6];
+        let shuffled_players = shuffled(&players, 7);
+        assert_eq!(shuffled_players, shuffled(&players, 7));
+
+        // The input order doesn't matter.
+        assert_eq!(shuffled_players, shuffled(&[4, 6, 1, 3, 5, 2], 7));
+
+        let mut sorted = shuffled_players.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, players);
+    }
+
+    #[test]
+    fn shuffle_varies_first_player()
+    {
+        let firsts: std::collections::HashSet<ClientId> = (0..32)
+            .map(|seed| shuffled(&[1, 2, 3, 4], seed)[0])
+            .collect();
+        assert!(firsts.len() > 1);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
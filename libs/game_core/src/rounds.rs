@@ -1,6 +1,5 @@
 use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::*;
-use bevy_girk_game_fw::GameSender;
 
 use crate::vis::*;
 use crate::*;
@@ -69,6 +68,15 @@ fn try_start_round(
 #[derive(Resource, Default, Debug, Deref)]
 pub(crate) struct GameRound(u32);
 
+impl GameRound
+{
+    /// Sets the round when resuming a game from a snapshot.
+    pub(crate) fn set_resumed(&mut self, round: u32)
+    {
+        self.0 = round;
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Schedule that runs at the end of a round.
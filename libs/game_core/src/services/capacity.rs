@@ -0,0 +1,112 @@
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use hexx::Hex;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Allocates each player's service capacity to the buildings that require services.
+///
+/// A building that can't get all the services it requires only receives the fraction it can use, which is bounded
+/// by its least-available service. Service buildings that require services provide capacity in proportion to the
+/// fraction they receive, so buildings are served in dependency order (see [`BuildingData::service_ranks`]): first
+/// service buildings by the rank of the service they provide, then all other buildings. Within each step buildings
+/// are served in tile order so allocation is deterministic.
+///
+/// Service buildings that aren't connected to their owner's HQ by the transport network (see [`connected_tiles`])
+/// provide reduced capacity, depending on their capacity use weight.
+///
+/// Returns the fraction of full operation (between 0 and 1) for each building with service requirements.
+/// Buildings without service requirements are not included.
+pub(crate) fn allocate_services<'a>(
+    buildings: &BuildingData,
+    transport: &TransportSettings,
+    connected: &HashMap<ClientId, HashSet<Hex>>,
+    metas: impl Iterator<Item = (Entity, &'a TileMeta, &'a TileOwner, &'a BuildingId)>,
+) -> HashMap<Entity, f64>
+{
+    let ranks = buildings.service_ranks().unwrap_or_else(|err| {
+        tracing::error!("failed ranking services: {err}");
+        HashMap::default()
+    });
+    let mut capacity = HashMap::<(ClientId, ServiceId), u64>::default();
+    let mut consumers = Vec::new();
+
+    for (entity, meta, owner, building_id) in metas {
+        let Some(spec) = buildings.get(building_id) else {
+            tracing::error!(?meta, "building {building_id:?} is missing from building data");
+            continue;
+        };
+
+        let mut required: Vec<(&ServiceId, u64)> = match &spec.build_cost {
+            BuildCost::Build { services, .. } => services.iter().map(|(id, amount)| (id, *amount)).collect(),
+            BuildCost::System => Vec::default(),
+        };
+        let provided = match &spec.building_type {
+            BuildingTypeSpec::Service { service, capacity, capacity_use_weight } => {
+                let is_connected = connected
+                    .get(&owner.0)
+                    .is_none_or(|tiles| tiles.contains(&meta.tile));
+                let capacity = match is_connected {
+                    true => *capacity,
+                    false => {
+                        let factor =
+                            (transport.unconnected_production_factor as f64).powf(*capacity_use_weight as f64);
+                        (*capacity as f64 * factor).floor() as u64
+                    }
+                };
+                Some((service, capacity))
+            }
+            _ => None,
+        };
+
+        if required.is_empty() {
+            if let Some((service, provided)) = provided {
+                let entry = capacity.entry((owner.0, service.clone())).or_default();
+                *entry = entry.saturating_add(provided);
+            }
+            continue;
+        }
+
+        let step = provided
+            .map(|(service, _)| ranks.get(service).copied().unwrap_or_default())
+            .unwrap_or(usize::MAX);
+        required.sort_unstable();
+        consumers.push((step, owner.0, meta.tile, entity, required, provided));
+    }
+
+    consumers.sort_unstable_by_key(|(step, owner, tile, ..)| (*step, *owner, tile.x, tile.y));
+
+    let mut allocation = HashMap::with_capacity(consumers.len());
+    for (_, owner, _, entity, required, provided) in consumers {
+        let fraction = required
+            .iter()
+            .map(|(service, amount)| {
+                let available = capacity
+                    .get(&(owner, (*service).clone()))
+                    .copied()
+                    .unwrap_or_default();
+                (available as f64 / *amount as f64).min(1.0)
+            })
+            .fold(1.0f64, f64::min);
+
+        for (service, amount) in required {
+            let Some(available) = capacity.get_mut(&(owner, service.clone())) else { continue };
+            let used = ((amount as f64 * fraction).ceil() as u64).min(*available);
+            *available -= used;
+        }
+
+        if let Some((service, provided)) = provided {
+            let provided = (provided as f64 * fraction).floor() as u64;
+            let entry = capacity.entry((owner, service.clone())).or_default();
+            *entry = entry.saturating_add(provided);
+        }
+
+        allocation.insert(entity, fraction);
+    }
+
+    allocation
+}
+
+//-------------------------------------------------------------------------------------------------------------------
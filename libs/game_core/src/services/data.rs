@@ -0,0 +1,60 @@
+use std::borrow::{Borrow, Cow};
+
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use utils::RootConfigs;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The canonical ID of a type of service.
+///
+/// Services are provided by service buildings and consumed each round by buildings that require them.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Reflect)]
+#[reflect(Hash, Deserialize)]
+pub struct ServiceId(Cow<'static, str>);
+
+impl ServiceId
+{
+    pub fn new(id: impl AsRef<str>) -> Self
+    {
+        Self(Cow::from(String::from(id.as_ref())))
+    }
+
+    pub fn get(&self) -> &str
+    {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ServiceId
+{
+    fn borrow(&self) -> &str
+    {
+        self.get()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Debug, Serialize, Deserialize, Clone, Deref)]
+pub struct ServiceData(HashSet<ServiceId>);
+
+impl ServiceData
+{
+    pub fn new(configs: &RootConfigs) -> Result<Self, String>
+    {
+        configs.get_type_from_file::<Self>("service_data")
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String>
+    {
+        if self.is_empty() {
+            return Err("ServiceData has no registered services".into());
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
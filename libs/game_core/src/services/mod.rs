@@ -0,0 +1,5 @@
+mod capacity;
+mod data;
+
+pub(crate) use capacity::*;
+pub use data::*;
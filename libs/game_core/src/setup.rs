@@ -11,29 +11,81 @@ use crate::*;
 fn setup_game(world: &mut World)
 {
     // extract initializer
-    let initializer = world
+    let mut initializer = world
         .remove_resource::<ProvGameInitializer>()
         .expect("ProvGameInitializer missing on startup");
+    let snapshot = initializer.snapshot.take();
 
     // resources
-    world.insert_resource::<GameRand>(GameRand::new(initializer.game_context.seed));
+    let seed = initializer.game_context.seed;
+    world.insert_resource::<GameRand>(GameRand::new(seed));
     world.insert_resource(initializer.game_context);
 
     // players
     // - player map
     // - player entities
+    // - player inventories
+    // - player sight
+    // - queued orders
+    // - pending notifications
     let mut client_entity_map = HashMap::<ClientId, Entity>::default();
+    let mut replay_players = Vec::with_capacity(initializer.players.len());
+    let starting_inventory = world
+        .get_resource::<StartingInventory>()
+        .cloned()
+        .expect("StartingInventory missing on startup");
+    let mut resumed_inventories: HashMap<ClientId, PlayerInventory> = snapshot
+        .iter()
+        .flat_map(|snapshot| snapshot.players.iter())
+        .map(|player| (player.client_id, player.inventory.clone()))
+        .collect();
 
     for (_, player_state) in initializer.players {
+        let client_id = player_state.id.id;
+
         // [ client id : entity ]
         let mut entity_commands = world.spawn_empty();
-        client_entity_map.insert(player_state.id.id, entity_commands.id());
+        client_entity_map.insert(client_id, entity_commands.id());
 
         // add player entity
+        let player_id = player_state.id;
+        replay_players.push(ReplayPlayer {
+            client_id,
+            name: player_state.name.name.clone(),
+            bot: initializer.bots.get(&client_id).copied(),
+        });
         entity_commands.insert(player_state);
+        if let Some(difficulty) = initializer.bots.get(&client_id) {
+            entity_commands.insert(Bot::new(*difficulty, seed as u64, player_id));
+        }
+
+        // add inventory entity, visible only to the owner and watchers
+        let inventory = resumed_inventories
+            .remove(&client_id)
+            .unwrap_or_else(|| PlayerInventory::new(client_id, &starting_inventory));
+        world.spawn((inventory, owner_visibility(client_id)));
+
+        // add sight entity, visible only to the owner and watchers
+        world.spawn((PlayerSight::new(client_id), owner_visibility(client_id)));
     }
 
+    spawn_queued_orders(world, client_entity_map.keys().copied());
+    world.insert_resource(PendingNotifications::new(client_entity_map.keys().copied()));
     world.insert_resource(PlayerMap::new(client_entity_map));
+
+    // replay
+    if let Some(mut recorder) = world.remove_resource::<ReplayRecorder>() {
+        recorder.set_context(world.resource::<GameContext>(), replay_players);
+        world.insert_resource(recorder);
+    }
+
+    // snapshots
+    if let Some(mut recorder) = world.get_resource_mut::<SnapshotRecorder>() {
+        recorder.set_user_ids(initializer.user_ids);
+    }
+    if let Some(snapshot) = snapshot {
+        resume_from_snapshot(world, snapshot);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -46,6 +98,7 @@ impl Plugin for GameSetupPlugin
     {
         app.insert_resource(GameMessageType::new::<GameMsg>())
             .insert_resource(ClientRequestHandler::new(handle_client_request))
+            .init_resource::<RequestRateLimiter>()
             .add_systems(Startup, setup_game);
     }
 }
@@ -0,0 +1,7 @@
+mod restore;
+mod saving;
+mod snapshot_file;
+
+pub(crate) use restore::*;
+pub use saving::*;
+pub use snapshot_file::*;
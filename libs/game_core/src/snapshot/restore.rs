@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Snapshot waiting to be restored once the map has been generated.
+#[derive(Resource)]
+pub(crate) struct PendingSnapshot(GameSnapshot);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Prepares the game to resume from a snapshot.
+///
+/// Players and their inventories must be set up separately. Tile metas are restored after map generation.
+pub(crate) fn resume_from_snapshot(world: &mut World, snapshot: GameSnapshot)
+{
+    tracing::info!(round = snapshot.round, game_time = ?snapshot.game_time, "resuming game from snapshot");
+
+    world
+        .resource_mut::<GameTime>()
+        .set_resume_time(snapshot.game_time);
+    world
+        .resource_mut::<GameRound>()
+        .set_resumed(snapshot.round);
+    // Start regions aren't placed again, so catch up to the original game's random sequence.
+    world
+        .resource_mut::<GameRand>()
+        .fast_forward(snapshot.rand_draws);
+
+    // Inputs from before the snapshot weren't recorded, so the replay would not reproduce the game.
+    if world.remove_resource::<ReplayRecorder>().is_some() {
+        tracing::warn!("replay recording is disabled for resumed games");
+    }
+
+    world.insert_resource(PendingSnapshot(snapshot));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns the tile metas saved in a pending snapshot.
+pub(crate) fn restore_snapshot(world: &mut World)
+{
+    let Some(PendingSnapshot(snapshot)) = world.remove_resource::<PendingSnapshot>() else { return };
+
+    let mut conditions = Vec::default();
+    for tile in snapshot.tiles {
+        let mut entity = world.spawn(TileMeta { tile: tile.tile });
+        if let Some(owner) = tile.owner {
+            entity.insert(owner);
+        }
+        if let Some(claims) = tile.claims {
+            entity.insert(claims);
+        }
+        if let Some(building) = tile.building {
+            entity.insert(building);
+        }
+        if let Some(condition) = tile.condition {
+            conditions.push((entity.id(), condition));
+        }
+    }
+
+    // Conditions are inserted after flushing so they override the defaults inserted alongside new buildings.
+    world.flush();
+    for (entity, condition) in conditions {
+        world.entity_mut(entity).insert(condition);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Run condition for systems that only run when starting a new game.
+pub(crate) fn is_new_game(pending: Option<Res<PendingSnapshot>>) -> bool
+{
+    pending.is_none()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
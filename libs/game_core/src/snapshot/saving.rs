@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for saving game snapshots.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotSettings
+{
+    /// If enabled, a snapshot is written at the start of each round and removed when the game ends.
+    pub enabled: bool,
+    /// Directory where snapshot files are written.
+    pub directory: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Information needed to make [`GameSnapshot`]s that isn't stored in the game state.
+///
+/// Inserted with the game data if snapshots are enabled.
+#[derive(Resource, Debug)]
+pub struct SnapshotRecorder
+{
+    config_hash: u64,
+    user_ids: HashMap<ClientId, u128>,
+}
+
+impl SnapshotRecorder
+{
+    pub(crate) fn new(config_hash: u64) -> Self
+    {
+        Self { config_hash, user_ids: HashMap::default() }
+    }
+
+    pub(crate) fn set_user_ids(&mut self, user_ids: HashMap<ClientId, u128>)
+    {
+        self.user_ids = user_ids;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn build_snapshot(
+    recorder: Res<SnapshotRecorder>,
+    ctx: Res<GameContext>,
+    game_time: Res<GameTime>,
+    round: Res<GameRound>,
+    rand: Res<GameRand>,
+    players: Query<(&PlayerId, &PlayerName, Option<&Bot>)>,
+    inventories: Query<&PlayerInventory>,
+    metas: Query<(
+        &TileMeta,
+        Option<&TileOwner>,
+        Option<&TileClaims>,
+        Option<&BuildingId>,
+        Option<&BuildingCondition>,
+    )>,
+) -> GameSnapshot
+{
+    let mut players: Vec<SnapshotPlayer> = players
+        .iter()
+        .filter_map(|(player_id, name, bot)| {
+            let client_id = player_id.id;
+            let Some(inventory) = inventories
+                .iter()
+                .find(|inventory| inventory.owner() == client_id)
+            else {
+                tracing::error!("client {client_id} is missing a player inventory");
+                return None;
+            };
+            Some(SnapshotPlayer {
+                client_id,
+                user_id: recorder
+                    .user_ids
+                    .get(&client_id)
+                    .copied()
+                    .unwrap_or_default(),
+                name: name.name.clone(),
+                bot: bot.map(|bot| bot.difficulty()),
+                inventory: inventory.clone(),
+            })
+        })
+        .collect();
+    players.sort_unstable_by_key(|player| player.client_id);
+
+    let mut tiles: Vec<SnapshotTile> = metas
+        .iter()
+        .map(|(meta, owner, claims, building, condition)| SnapshotTile {
+            tile: meta.tile,
+            owner: owner.copied(),
+            claims: claims.cloned(),
+            building: building.cloned(),
+            condition: condition.copied(),
+        })
+        .collect();
+    tiles.sort_unstable_by_key(|tile| (tile.tile.x, tile.tile.y));
+
+    GameSnapshot {
+        version: SNAPSHOT_VERSION,
+        game_id: ctx.game_id,
+        seed: ctx.seed,
+        duration_config: ctx.duration_config,
+        config_hash: recorder.config_hash,
+        round: **round,
+        game_time: game_time.elapsed(),
+        rand_draws: rand.draws(),
+        players,
+        tiles,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes a snapshot of the game at the start of a round, replacing the previous snapshot.
+fn save_snapshot(world: &mut World)
+{
+    if !world.contains_resource::<SnapshotRecorder>() {
+        return;
+    }
+    let snapshot = world.syscall((), build_snapshot);
+
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let json = match snapshot.to_json() {
+            Ok(json) => json,
+            Err(err) => {
+                tracing::error!("failed saving snapshot: {err}");
+                return;
+            }
+        };
+
+        // Write to a temporary file first so a crash mid-write doesn't corrupt the latest snapshot.
+        let directory = &world.resource::<SnapshotSettings>().directory;
+        let path = snapshot_path(directory, snapshot.game_id);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::create_dir_all(directory)
+            .and_then(|_| std::fs::write(&tmp_path, json))
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(err) = result {
+            tracing::error!(?path, "failed writing snapshot file: {err:?}");
+            return;
+        }
+        tracing::info!(?path, round = snapshot.round, "saved snapshot");
+    }
+
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = snapshot;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes the game's snapshot once the game is over, since it can no longer be resumed.
+fn remove_snapshot(settings: Res<SnapshotSettings>, ctx: Res<GameContext>)
+{
+    #[cfg(not(target_family = "wasm"))]
+    {
+        if let Err(err) = GameSnapshot::remove(&settings.directory, ctx.game_id) {
+            tracing::error!("{err}");
+        }
+    }
+
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = (settings, ctx);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct SnapshotPlugin;
+
+impl Plugin for SnapshotPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        let enabled = |settings: Res<SnapshotSettings>| settings.enabled;
+
+        app.add_systems(RoundStart, save_snapshot.run_if(enabled))
+            .add_systems(PostStartup, restore_snapshot.after(place_start_regions))
+            .add_systems(OnEnter(GameState::End), remove_snapshot.run_if(enabled));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Version of the snapshot file format. Increment this when the format changes.
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the path of the latest snapshot for a game.
+#[cfg(not(target_family = "wasm"))]
+pub fn snapshot_path(directory: &str, game_id: u64) -> std::path::PathBuf
+{
+    std::path::Path::new(directory).join(format!("snapshot-{game_id}.json"))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A player in a snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotPlayer
+{
+    pub client_id: ClientId,
+    /// The player's server-side user id. Used to give reconnecting users their old client ids.
+    ///
+    /// Zero for bots.
+    pub user_id: u128,
+    pub name: String,
+    pub bot: Option<BotDifficulty>,
+    pub inventory: PlayerInventory,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The state of a tile meta entity in a snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotTile
+{
+    pub tile: Hex,
+    pub owner: Option<TileOwner>,
+    pub claims: Option<TileClaims>,
+    pub building: Option<BuildingId>,
+    pub condition: Option<BuildingCondition>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game state saved at a round boundary, used to resume a game after the game instance dies.
+///
+/// The map itself isn't saved since it can be regenerated from the seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshot
+{
+    pub version: u32,
+    pub game_id: u64,
+    pub seed: u128,
+    pub duration_config: GameDurationConfig,
+    /// Hash of the game data the game was started with. See [`config_hash`].
+    pub config_hash: u64,
+    /// The round that just started.
+    pub round: u32,
+    /// Game time when the round started.
+    pub game_time: Duration,
+    /// Number of values drawn from [`GameRand`] when the round started.
+    pub rand_draws: u64,
+    pub players: Vec<SnapshotPlayer>,
+    pub tiles: Vec<SnapshotTile>,
+}
+
+impl GameSnapshot
+{
+    /// Checks that the snapshot can be resumed with the given game data.
+    pub fn validate(&self, game_data: &GameData) -> Result<(), String>
+    {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(format!(
+                "snapshot has version {} but only version {SNAPSHOT_VERSION} is supported",
+                self.version
+            ));
+        }
+        let hash = config_hash(game_data)?;
+        if hash != self.config_hash {
+            return Err(format!(
+                "snapshot config hash {} does not match the current game data (hash {hash})",
+                self.config_hash
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String, String>
+    {
+        serde_json::to_string(self).map_err(|err| format!("failed serializing snapshot: {err:?}"))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String>
+    {
+        serde_json::from_str(json).map_err(|err| format!("failed deserializing snapshot: {err:?}"))
+    }
+
+    /// Loads the latest snapshot for a game.
+    ///
+    /// Returns `None` if there is no snapshot for the game.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load(directory: &str, game_id: u64) -> Result<Option<Self>, String>
+    {
+        let path = snapshot_path(directory, game_id);
+        let json = match std::fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(format!("failed reading snapshot {path:?}: {err:?}")),
+        };
+        let snapshot = Self::from_json(&json)?;
+        if snapshot.game_id != game_id {
+            return Err(format!("snapshot {path:?} is for game {} instead of {game_id}", snapshot.game_id));
+        }
+
+        Ok(Some(snapshot))
+    }
+
+    /// Removes the latest snapshot for a game, if there is one.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn remove(directory: &str, game_id: u64) -> Result<(), String>
+    {
+        let path = snapshot_path(directory, game_id);
+        match std::fs::remove_file(&path) {
+            Ok(()) => {
+                tracing::info!(?path, "removed snapshot");
+                Ok(())
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(format!("failed removing snapshot file {path:?}: {err:?}")),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
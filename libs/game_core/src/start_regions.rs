@@ -0,0 +1,376 @@
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A potential HQ location.
+struct StartCandidate
+{
+    tile: Hex,
+    /// Number of each of [`StartRegionSettings::resource_tiles`] around the tile.
+    profile: Vec<u32>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sum of the ranges of resource tile counts across regions.
+fn region_unfairness(candidates: &[StartCandidate], regions: &[usize]) -> u32
+{
+    let Some(first) = regions.first() else { return 0 };
+    (0..candidates[*first].profile.len())
+        .map(|i| {
+            let counts = regions.iter().map(|region| candidates[*region].profile[i]);
+            counts.clone().max().unwrap_or_default() - counts.min().unwrap_or_default()
+        })
+        .sum()
+}
+
+fn profile_difference(a: &[u32], b: &[u32]) -> u32
+{
+    a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).sum()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tries to find start regions that are at least `min_distance` apart.
+///
+/// Each attempt starts from a random anchor region, then adds the spaced-out regions whose resources most closely
+/// match the anchor. The fairest attempt is returned.
+fn find_start_regions(
+    rand: &mut GameRand,
+    attempts: u16,
+    min_distance: u32,
+    num_players: usize,
+    candidates: &[StartCandidate],
+) -> Option<Vec<usize>>
+{
+    let mut best: Option<(u32, Vec<usize>)> = None;
+
+    for _ in 0..attempts {
+        let anchor = (rand.next() % candidates.len() as u64) as usize;
+        let mut regions = vec![anchor];
+
+        while regions.len() < num_players {
+            let next = (0..candidates.len())
+                .filter(|candidate| {
+                    regions.iter().all(|region| {
+                        *region != *candidate
+                            && candidates[*region]
+                                .tile
+                                .unsigned_distance_to(candidates[*candidate].tile)
+                                >= min_distance
+                    })
+                })
+                .min_by_key(|candidate| {
+                    profile_difference(&candidates[*candidate].profile, &candidates[anchor].profile)
+                });
+            let Some(next) = next else { break };
+            regions.push(next);
+        }
+        if regions.len() < num_players {
+            continue;
+        }
+
+        let unfairness = region_unfairness(candidates, &regions);
+        if best.as_ref().is_none_or(|(best, _)| unfairness < *best) {
+            best = Some((unfairness, regions));
+        }
+    }
+
+    best.map(|(_, regions)| regions)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Picks a start region for each player, places their HQs, and offers nearby tiles for tile select.
+///
+/// Panics if the map doesn't have an HQ location for each player, since the game can't be played without start
+/// regions. Game factories should reject such maps with [`StartRegionSettings::check_map`] before starting the
+/// game.
+pub(crate) fn place_start_regions(
+    mut c: Commands,
+    mut rand: ResMut<GameRand>,
+    settings: Res<StartRegionSettings>,
+    grid: Res<HexGrid>,
+    tiles: Query<(&TileId, Has<OwnableTile>, Has<WaterTile>), With<MapTile>>,
+    players: Query<&PlayerId>,
+)
+{
+    let mut clients: Vec<ClientId> = players.iter().map(|player_id| player_id.id).collect();
+    clients.sort_unstable();
+    if clients.is_empty() {
+        return;
+    }
+
+    let can_select = |tile: &Hex| -> bool {
+        grid.tiles
+            .get(tile)
+            .and_then(|entity| tiles.get(*entity).ok())
+            .is_some_and(|(_, is_ownable, _)| is_ownable)
+    };
+
+    // Collect candidate HQ locations, in random order so ties between equally-good regions are broken randomly.
+    let mut coords: Vec<Hex> = grid.tiles.keys().copied().collect();
+    coords.sort_unstable_by_key(|tile| (tile.x, tile.y));
+    let mut candidates: Vec<StartCandidate> = coords
+        .into_iter()
+        .filter(|tile| {
+            let Some((_, is_ownable, is_water)) = grid.tiles.get(tile).and_then(|e| tiles.get(*e).ok()) else {
+                return false;
+            };
+            // The whole selectable region must be inside the map.
+            is_ownable
+                && !is_water
+                && tile
+                    .range(settings.selectable_radius)
+                    .all(|nearby| grid.tiles.contains_key(&nearby))
+        })
+        .map(|tile| {
+            let profile = settings
+                .resource_tiles
+                .iter()
+                .map(|resource_tile| {
+                    tile.range(settings.fairness_radius)
+                        .filter_map(|nearby| grid.tiles.get(&nearby))
+                        .filter_map(|entity| tiles.get(*entity).ok())
+                        .filter(|(tile_id, ..)| *tile_id == resource_tile)
+                        .count() as u32
+                })
+                .collect();
+            StartCandidate { tile, profile }
+        })
+        .collect();
+    for i in (1..candidates.len()).rev() {
+        let j = (rand.next() % (i as u64 + 1)) as usize;
+        candidates.swap(i, j);
+    }
+    if candidates.len() < clients.len() {
+        panic!(
+            "failed placing start regions for {} players, only {} candidate HQ tiles",
+            clients.len(),
+            candidates.len()
+        );
+    }
+
+    // Find regions, relaxing the spacing if necessary.
+    let mut min_distance = settings.min_region_distance;
+    let regions = loop {
+        if let Some(regions) =
+            find_start_regions(&mut rand, settings.attempts, min_distance, clients.len(), &candidates)
+        {
+            break regions;
+        }
+        if min_distance == 0 {
+            panic!("failed placing start regions for {} players", clients.len());
+        }
+        min_distance /= 2;
+        tracing::warn!("failed finding spaced-out start regions, retrying with min distance {min_distance}");
+    };
+
+    // Place HQs first so they aren't offered to other players.
+    let hqs: Vec<Hex> = regions
+        .iter()
+        .map(|region| candidates[*region].tile)
+        .collect();
+    let mut used: HashSet<Hex> = hqs.iter().copied().collect();
+    for (client_id, hq) in clients.iter().zip(hqs.iter()) {
+        tracing::info!(?hq, "placing HQ for client {client_id}");
+        c.spawn((TileMeta { tile: *hq }, TileOwner(*client_id), BuildingId::new("hq-1")));
+    }
+
+    for (client_id, hq) in clients.iter().zip(hqs.iter()) {
+        for tile in hq.range(settings.selectable_radius) {
+            if !can_select(&tile) || !used.insert(tile) {
+                continue;
+            }
+            c.spawn((
+                TileMeta { tile },
+                SelectableTile { client: *client_id },
+                owner_visibility(*client_id),
+            ));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for choosing player start regions.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct StartRegionSettings
+{
+    /// Minimum hex distance between player HQs.
+    pub min_region_distance: u32,
+    /// Tiles within this hex distance of a player's HQ can be selected by the player during tile select.
+    pub selectable_radius: u32,
+    /// Resource tiles within this hex distance of a player's HQ are compared when checking fairness.
+    pub fairness_radius: u32,
+    /// Tiles that should be balanced between start regions.
+    pub resource_tiles: Vec<TileId>,
+    /// Number of attempts at finding fair start regions. The fairest attempt is used.
+    pub attempts: u16,
+}
+
+impl StartRegionSettings
+{
+    pub(crate) fn validate(&self, tiles: &TileData, buildings: &BuildingData) -> Result<(), String>
+    {
+        if self.selectable_radius == 0 || self.fairness_radius == 0 {
+            return Err("StartRegionSettings has a radius of 0".into());
+        }
+        if self.min_region_distance <= self.selectable_radius * 2 {
+            return Err("StartRegionSettings min region distance doesn't separate selectable regions".into());
+        }
+        if self.attempts == 0 {
+            return Err("StartRegionSettings has 0 attempts".into());
+        }
+        for tile_id in self.resource_tiles.iter() {
+            if !tiles.contains_key(tile_id) {
+                return Err(format!("StartRegionSettings has unregistered resource tile {:?}", tile_id));
+            }
+        }
+        if buildings.get_tileselect_tiles().is_none() {
+            return Err("StartRegionSettings requires an hq-1 building with Hq type".into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that maps made with `mapgen` have room for a separate selectable region for each player.
+    ///
+    /// Only the map's shape is checked, so regions can still fail to fit if the generated map has too few ownable
+    /// tiles.
+    pub fn check_fits(&self, mapgen: &MapGenSettings, num_players: usize) -> Result<(), String>
+    {
+        let coords = mapgen.map_shape.coords(mapgen.map_dimension);
+        let edges = find_edge_tiles(&coords, mapgen.edge_buffer);
+        let interior: HashSet<Hex> = coords
+            .iter()
+            .zip(edges)
+            .filter(|(_, is_edge)| !is_edge)
+            .map(|(coord, _)| *coord)
+            .collect();
+
+        // Greedily place non-overlapping regions in map order.
+        let mut hqs: Vec<Hex> = Vec::default();
+        for coord in coords.iter() {
+            if hqs.len() >= num_players {
+                break;
+            }
+            // The server doesn't spawn edge tiles, so the whole selectable region must be inside the edge buffer.
+            if !coord
+                .range(self.selectable_radius)
+                .all(|nearby| interior.contains(&nearby))
+                || hqs
+                    .iter()
+                    .any(|hq| hq.unsigned_distance_to(*coord) <= self.selectable_radius * 2)
+            {
+                continue;
+            }
+            hqs.push(*coord);
+        }
+        if hqs.len() < num_players {
+            return Err(format!(
+                "map dimension {} only has room for {} of {num_players} start regions",
+                mapgen.map_dimension,
+                hqs.len()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a generated map has an HQ location for each player.
+    ///
+    /// Spacing between start regions is relaxed when needed, so regions can always be placed if there are enough
+    /// HQ locations. Unlike [`Self::check_fits`], this accounts for the map's terrain.
+    pub fn check_map(
+        &self,
+        tiles: &[GeneratedTile],
+        tile_data: &TileData,
+        num_players: usize,
+    ) -> Result<(), String>
+    {
+        let interior: HashSet<Hex> = tiles
+            .iter()
+            .filter(|tile| !tile.is_edge)
+            .map(|tile| tile.coord)
+            .collect();
+        let num_candidates = tiles
+            .iter()
+            .filter(|tile| {
+                !tile.is_edge
+                    && tile_data
+                        .get(&tile.id)
+                        .is_some_and(|spec| spec.is_ownable && !spec.is_water_tile)
+                    && tile
+                        .coord
+                        .range(self.selectable_radius)
+                        .all(|nearby| interior.contains(&nearby))
+            })
+            .count();
+        if num_candidates < num_players {
+            return Err(format!(
+                "generated map only has {num_candidates} HQ locations for {num_players} players"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct StartRegionsPlugin;
+
+impl Plugin for StartRegionsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(PostStartup, place_start_regions.after(generate_map).run_if(is_new_game));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn settings() -> StartRegionSettings
+    {
+        StartRegionSettings {
+            min_region_distance: 16,
+            selectable_radius: 3,
+            fairness_radius: 4,
+            resource_tiles: Vec::default(),
+            attempts: 1,
+        }
+    }
+
+    fn mapgen(map_dimension: i32) -> MapGenSettings
+    {
+        MapGenSettings {
+            hex_scale: Vec2::ONE,
+            map_dimension,
+            edge_buffer: 2,
+            map_shape: MapShape::Hexagon,
+            terrain_mode: TerrainMode::default(),
+        }
+    }
+
+    #[test]
+    fn start_regions_fit_map()
+    {
+        // A radius-5 hexagon only has room for one region of radius 3 inside its edge buffer.
+        assert!(settings().check_fits(&mapgen(5), 1).is_ok());
+        assert!(settings().check_fits(&mapgen(5), 2).is_err());
+        assert!(settings().check_fits(&mapgen(40), 8).is_ok());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
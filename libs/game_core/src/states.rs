@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use bevy_girk_game_fw::*;
+use bevy_girk_game_fw::{GameEndFlag, GameOverReport};
 use bevy_girk_utils::apply_state_transitions;
 use serde::{Deserialize, Serialize};
 
@@ -33,12 +33,14 @@ fn update_game_state(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn set_game_end_flag(
+pub(crate) fn set_game_end_flag(
     ctx: Res<GameContext>,
     game_time: Res<GameTime>,
     round: Res<GameRound>,
     players: Query<&PlayerId>,
     mut game_end_flag: ResMut<GameEndFlag>,
+    recorder: Option<ResMut<GameRecorder>>,
+    replay: Option<ResMut<ReplayRecorder>>,
 )
 {
     // collect player reports
@@ -57,6 +59,12 @@ fn set_game_end_flag(
 
     // serialize it
     let game_over_report_final = GameOverReport::new(&game_over_report);
+    if let Some(mut replay) = replay {
+        replay.set_game_over_report(game_over_report.clone());
+    }
+    if let Some(mut recorder) = recorder {
+        recorder.set_game_over_report(game_over_report);
+    }
 
     // set the game end flag
     game_end_flag.set(game_over_report_final);
@@ -0,0 +1,285 @@
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::vis::*;
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Claims an unowned tile for a player.
+///
+/// Claims are resolved at the end of each round. If fog of war is enabled, only tiles in the player's sight can be
+/// claimed.
+pub(crate) fn handle_claim_tile(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    settings: Res<TileClaimSettings>,
+    fog: Res<FogOfWarSettings>,
+    sights: Query<&PlayerSight>,
+    tiles: Query<(Has<OwnableTile>, Option<&AttachedMeta>), With<MapTile>>,
+    mut metas: Query<(Has<TileOwner>, Option<&mut TileClaims>), With<TileMeta>>,
+    inventories: Query<&PlayerInventory>,
+) -> Result<(), RejectionReason>
+{
+    let Some(gold) = inventories
+        .iter()
+        .find(|inventory| inventory.owner() == client_id)
+        .map(|inventory| inventory.gold())
+    else {
+        tracing::error!("client {client_id} is missing a player inventory");
+        return Err(RejectionReason::Invalid);
+    };
+    let can_afford = |age: u16| -> Result<(), RejectionReason> {
+        let cost = settings.claim_cost(age);
+        if cost > gold {
+            tracing::debug!("client {client_id} tried to claim a tile but can't afford the claim cost");
+            return Err(RejectionReason::InsufficientGold { shortfall: cost - gold });
+        }
+        Ok(())
+    };
+
+    let Some(tile_entity) = grid.tiles.get(&tile).copied() else {
+        tracing::debug!(?tile, "client {client_id} tried to claim a tile that doesn't exist");
+        return Err(RejectionReason::InvalidTile);
+    };
+    if fog.enabled
+        && !sights
+            .iter()
+            .any(|sight| sight.owner() == client_id && sight.contains(&tile))
+    {
+        tracing::debug!(?tile, "client {client_id} tried to claim a tile that isn't in sight");
+        return Err(RejectionReason::InvalidTile);
+    }
+    let Ok((is_ownable, attached)) = tiles.get(tile_entity) else {
+        tracing::error!(?tile, ?tile_entity, "tile entity is missing MapTile");
+        return Err(RejectionReason::Invalid);
+    };
+    if !is_ownable {
+        tracing::debug!(?tile, "client {client_id} tried to claim an unownable tile");
+        return Err(RejectionReason::InvalidTile);
+    }
+
+    let Some(meta) = attached.map(|attached| **attached) else {
+        can_afford(0)?;
+        c.spawn((TileMeta { tile }, TileClaims::new(client_id)));
+        return Ok(());
+    };
+    let Ok((is_owned, claims)) = metas.get_mut(meta) else {
+        tracing::error!(?tile, ?meta, "tile meta entity is missing TileMeta");
+        return Err(RejectionReason::Invalid);
+    };
+    if is_owned {
+        tracing::debug!(?tile, "client {client_id} tried to claim an owned tile");
+        return Err(RejectionReason::TileAlreadyOwned);
+    }
+
+    match claims {
+        Some(mut claims) => {
+            can_afford(claims.age())?;
+            if !claims.add_claimant(client_id) {
+                tracing::debug!(?tile, "client {client_id} tried to claim a tile it already claimed");
+                return Err(RejectionReason::AlreadyClaimed);
+            }
+        }
+        None => {
+            can_afford(0)?;
+            c.entity(meta).insert(TileClaims::new(client_id));
+        }
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes a player's claim on a tile.
+pub(crate) fn handle_unclaim_tile(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    grid: Res<HexGrid>,
+    tiles: Query<&AttachedMeta, With<MapTile>>,
+    mut claims: Query<&mut TileClaims>,
+) -> Result<(), RejectionReason>
+{
+    let Some(mut claims) = grid
+        .tiles
+        .get(&tile)
+        .and_then(|tile_entity| tiles.get(*tile_entity).ok())
+        .and_then(|attached| claims.get_mut(**attached).ok())
+    else {
+        tracing::debug!(?tile, "client {client_id} tried to unclaim a tile that has no claims");
+        return Err(RejectionReason::NotClaimed);
+    };
+    if !claims.remove_claimant(client_id) {
+        tracing::debug!(?tile, "client {client_id} tried to unclaim a tile it didn't claim");
+        return Err(RejectionReason::NotClaimed);
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resolves tile claims at the end of a round.
+///
+/// - Tiles with one claimant are given to that claimant if they can pay the claim cost.
+/// - Contested tiles carry over to the next round with a higher claim cost.
+/// - Tiles with no claimants are released.
+///
+/// If fog of war is enabled, each player is only told about results for tiles in their sight or that they claimed.
+/// Watchers are told about all results.
+pub(crate) fn resolve_tile_claims(
+    mut c: Commands,
+    round: Res<GameRound>,
+    settings: Res<TileClaimSettings>,
+    fog: Res<FogOfWarSettings>,
+    sights: Query<&PlayerSight>,
+    mut claims: Query<(Entity, &TileMeta, &mut TileClaims)>,
+    mut inventories: Query<&mut PlayerInventory>,
+    mut sender: GameSender,
+)
+{
+    let mut results = Vec::default();
+
+    for (meta_entity, meta, mut claims) in claims.iter_mut() {
+        let cost = settings.claim_cost(claims.age());
+        let claimants = claims.next_round();
+
+        match claimants.len() {
+            0 => {
+                c.entity(meta_entity).despawn();
+            }
+            1 => {
+                let claimant = claimants.iter().next().copied().unwrap();
+                let paid = get_inventory_mut(&mut inventories, claimant)
+                    .map(|mut inventory| inventory.try_spend_gold(cost).is_ok())
+                    .unwrap_or_default();
+                if !paid {
+                    c.entity(meta_entity).despawn();
+                    results.push(TileClaimResult::Unaffordable { tile: meta.tile, claimant, cost });
+                    continue;
+                }
+                c.entity(meta_entity)
+                    .remove::<TileClaims>()
+                    .insert(TileOwner(claimant));
+                results.push(TileClaimResult::Won { tile: meta.tile, owner: claimant, cost });
+            }
+            _ => {
+                let mut claimants: Vec<ClientId> = claimants.into_iter().collect();
+                claimants.sort_unstable();
+                for claimant in claimants.iter() {
+                    claims.add_claimant(*claimant);
+                }
+                let next_cost = settings.claim_cost(claims.age());
+                results.push(TileClaimResult::Contested { tile: meta.tile, claimants, next_cost });
+            }
+        }
+    }
+
+    if results.is_empty() {
+        return;
+    }
+
+    results.sort_unstable_by_key(|result| {
+        let tile = result.tile();
+        (tile.x, tile.y)
+    });
+    if !fog.enabled {
+        sender.send_to_all(GameMsg::TileClaimResults { round: **round, results });
+        return;
+    }
+
+    for sight in sights.iter() {
+        let owner = sight.owner();
+        let visible: Vec<TileClaimResult> = results
+            .iter()
+            .filter(|result| result.involves(owner) || sight.contains(&result.tile()))
+            .cloned()
+            .collect();
+        if visible.is_empty() {
+            continue;
+        }
+        sender.send_to_client(GameMsg::TileClaimResults { round: **round, results: visible }, owner);
+    }
+    sender.send(GameMsg::TileClaimResults { round: **round, results }, vis!(Watcher));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for claiming unowned tiles during play.
+#[derive(Resource, Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct TileClaimSettings
+{
+    /// Gold cost of a claim that is resolved in the round it was made.
+    pub base_cost: u64,
+    /// Additional gold cost for each round a claim is contested.
+    pub cost_per_age: u64,
+}
+
+impl TileClaimSettings
+{
+    /// Gets the cost of a claim with the given claiming age.
+    pub fn claim_cost(&self, age: u16) -> u64
+    {
+        self.base_cost
+            .saturating_add(self.cost_per_age.saturating_mul(age as u64))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The outcome of claims on a tile at the end of a round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TileClaimResult
+{
+    /// The tile had one claimant, who is now the owner.
+    Won
+    {
+        tile: Hex, owner: ClientId, cost: u64
+    },
+    /// The tile had one claimant, who couldn't pay the claim cost. The tile is released.
+    Unaffordable
+    {
+        tile: Hex, claimant: ClientId, cost: u64
+    },
+    /// The tile had multiple claimants, and the claims carry over to the next round.
+    Contested
+    {
+        tile: Hex, claimants: Vec<ClientId>, next_cost: u64
+    },
+}
+
+impl TileClaimResult
+{
+    pub fn tile(&self) -> Hex
+    {
+        match self {
+            Self::Won { tile, .. } | Self::Unaffordable { tile, .. } | Self::Contested { tile, .. } => *tile,
+        }
+    }
+
+    /// Checks if a client claimed the tile.
+    pub fn involves(&self, client_id: ClientId) -> bool
+    {
+        match self {
+            Self::Won { owner, .. } => *owner == client_id,
+            Self::Unaffordable { claimant, .. } => *claimant == client_id,
+            Self::Contested { claimants, .. } => claimants.contains(&client_id),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TileClaimsPlugin;
+
+impl Plugin for TileClaimsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(RoundEnd, resolve_tile_claims);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
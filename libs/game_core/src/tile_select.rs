@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use hexx::Hex;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the selectable tile meta of a tile offered to a player during tile select.
+fn get_selectable_meta(
+    client_id: ClientId,
+    tile: Hex,
+    grid: &HexGrid,
+    tiles: &Query<&AttachedMeta, With<MapTile>>,
+    selectable: &Query<(&SelectableTile, Option<&TileOwner>)>,
+) -> Option<(Entity, bool)>
+{
+    let meta = grid
+        .tiles
+        .get(&tile)
+        .and_then(|tile_entity| tiles.get(*tile_entity).ok())
+        .map(|attached| **attached)?;
+    let (selectable, owner) = selectable.get(meta).ok()?;
+    if selectable.client != client_id {
+        return None;
+    }
+
+    Some((meta, owner.is_some()))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Selects a tile for a player during tile select.
+///
+/// Only tiles offered to the player near their HQ can be selected (see [`SelectableTile`]). Selected tiles are
+/// only visible to the selecting player until tile select ends.
+pub(crate) fn handle_select_tile(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    buildings: Res<BuildingData>,
+    tiles: Query<&AttachedMeta, With<MapTile>>,
+    selectable: Query<(&SelectableTile, Option<&TileOwner>)>,
+) -> Result<(), RejectionReason>
+{
+    let Some((meta, is_selected)) = get_selectable_meta(client_id, tile, &grid, &tiles, &selectable) else {
+        tracing::debug!(?tile, "client {client_id} tried to select a tile that isn't selectable");
+        return Err(RejectionReason::InvalidTile);
+    };
+    if is_selected {
+        tracing::debug!(?tile, "client {client_id} tried to select a tile it already selected");
+        return Err(RejectionReason::TileAlreadyOwned);
+    }
+
+    // Check the player's tile allowance (excludes the player's HQ).
+    let Some(total_tiles_allowed) = buildings.get_tileselect_tiles() else {
+        tracing::error!("failed getting tile select allowance from building data");
+        return Err(RejectionReason::Invalid);
+    };
+    let num_selected = selectable
+        .iter()
+        .filter(|(selectable, owner)| selectable.client == client_id && owner.is_some())
+        .count();
+    if num_selected >= total_tiles_allowed as usize {
+        tracing::debug!(?tile, "client {client_id} tried to select a tile but has no remaining tile allowance");
+        return Err(RejectionReason::TileLimitReached);
+    }
+
+    // Mark the tile as owned.
+    c.entity(meta).insert(TileOwner(client_id));
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Deselects a tile previously selected by a player during tile select.
+pub(crate) fn handle_deselect_tile(
+    In((_player_entity, client_id, tile)): In<(Entity, ClientId, Hex)>,
+    mut c: Commands,
+    grid: Res<HexGrid>,
+    tiles: Query<&AttachedMeta, With<MapTile>>,
+    selectable: Query<(&SelectableTile, Option<&TileOwner>)>,
+) -> Result<(), RejectionReason>
+{
+    let Some((meta, true)) = get_selectable_meta(client_id, tile, &grid, &tiles, &selectable) else {
+        tracing::debug!(?tile, "client {client_id} tried to deselect a tile it didn't select");
+        return Err(RejectionReason::TileNotOwned);
+    };
+
+    c.entity(meta).remove::<TileOwner>();
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes all tiles selected during tile select visible to other players, and cleans up tiles that weren't
+/// selected.
+fn reveal_selected_tiles(
+    mut c: Commands,
+    fog: Res<FogOfWarSettings>,
+    selectable: Query<(Entity, &TileMeta, Has<TileOwner>), With<SelectableTile>>,
+)
+{
+    for (meta, tile_meta, is_selected) in selectable.iter() {
+        if !is_selected {
+            c.entity(meta).despawn();
+            continue;
+        }
+        c.entity(meta)
+            .remove::<SelectableTile>()
+            .insert(fog.tile_visibility(tile_meta.tile));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TileSelectPlugin;
+
+impl Plugin for TileSelectPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(OnExit(GameState::TileSelect), reveal_selected_tiles);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -20,13 +20,16 @@ fn update_game_time(time: Res<Time>, mut gametime: ResMut<GameTime>)
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Time elapsed in the game.
 #[derive(Resource, Default, Debug)]
-pub(crate) struct GameTime
+pub struct GameTime
 {
     /// Time the game started relative to when the app was constructed.
     start_time: Duration,
     /// Time elapsed since the game started.
     game_time: Duration,
+    /// Game time when the game was resumed from a snapshot.
+    resume_time: Duration,
     /// Amount of time the clock was accelerated.
     #[cfg(feature = "commands")]
     time_skip: Duration,
@@ -43,6 +46,13 @@ impl GameTime
         self.start_time = start_time;
     }
 
+    /// Sets the game time to resume from. Must be called before the game is initialized.
+    pub(crate) fn set_resume_time(&mut self, resume_time: Duration)
+    {
+        self.resume_time = resume_time;
+        self.game_time = resume_time;
+    }
+
     pub(crate) fn update(&mut self, app_time: Duration)
     {
         #[cfg(feature = "commands")]
@@ -51,13 +61,16 @@ impl GameTime
                 self.pause_elapsed = app_time.saturating_sub(
                     self.game_time
                         .saturating_sub(self.time_skip)
+                        .saturating_sub(self.resume_time)
                         .saturating_add(self.start_time),
                 );
                 return;
             }
         }
 
-        self.game_time = app_time.saturating_sub(self.start_time);
+        self.game_time = app_time
+            .saturating_sub(self.start_time)
+            .saturating_add(self.resume_time);
 
         #[cfg(feature = "commands")]
         {
@@ -87,10 +100,23 @@ impl GameTime
         self.pause_elapsed = Duration::default();
     }
 
-    pub(crate) fn elapsed(&self) -> Duration
+    pub fn elapsed(&self) -> Duration
     {
         self.game_time
     }
+
+    /// Whether the game clock is paused by a command.
+    pub fn is_paused(&self) -> bool
+    {
+        #[cfg(feature = "commands")]
+        {
+            self.paused
+        }
+        #[cfg(not(feature = "commands"))]
+        {
+            false
+        }
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,223 @@
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Settings for transport networks.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct TransportSettings
+{
+    /// Fraction of normal production (between 0 and 1) for production buildings that aren't connected to their
+    /// owner's HQ by the transport network. Unconnected service buildings are also penalized, see
+    /// [`BuildingTypeSpec::Service`].
+    pub unconnected_production_factor: f32,
+}
+
+impl TransportSettings
+{
+    pub(crate) fn validate(&self) -> Result<(), String>
+    {
+        if !(0.0..=1.0).contains(&self.unconnected_production_factor) {
+            return Err(format!(
+                "TransportSettings unconnected production factor {} is not between 0 and 1",
+                self.unconnected_production_factor
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component with the condition of a transport building.
+///
+/// Condition is between 0 and 1. Transport buildings decay each round they aren't maintained. Their coverage
+/// radius shrinks in proportion to their condition, and they stop covering tiles once their condition reaches 0.
+#[derive(Component, Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildingCondition(f32);
+
+impl BuildingCondition
+{
+    pub fn get(&self) -> f32
+    {
+        self.0
+    }
+
+    pub fn is_in_disrepair(&self) -> bool
+    {
+        self.0 <= 0.0
+    }
+
+    /// Scales a transport building's radius by its condition.
+    ///
+    /// Buildings that aren't in disrepair always cover a radius of at least 1.
+    pub fn scale_radius(&self, radius: u32) -> u32
+    {
+        if self.is_in_disrepair() {
+            return 0;
+        }
+        ((radius as f32 * self.0).ceil() as u32).max(1)
+    }
+}
+
+impl Default for BuildingCondition
+{
+    fn default() -> Self
+    {
+        Self(1.0)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Collects the tiles connected to each player's HQ by their transport network.
+///
+/// A transport building covers all tiles within its radius, scaled by its [`BuildingCondition`]. Transport
+/// buildings are connected to the network if their coverage contains the HQ or overlaps the coverage of another
+/// connected transport building. Transport buildings in disrepair are ignored. Players without an HQ are not
+/// included.
+pub(crate) fn connected_tiles<'a>(
+    buildings: &BuildingData,
+    metas: impl Iterator<
+        Item = (
+            &'a TileMeta,
+            &'a TileOwner,
+            &'a BuildingId,
+            Option<&'a BuildingCondition>,
+        ),
+    >,
+) -> HashMap<ClientId, HashSet<Hex>>
+{
+    let mut hqs = HashMap::<ClientId, Vec<Hex>>::default();
+    let mut transports = HashMap::<ClientId, Vec<(Hex, u32)>>::default();
+
+    for (meta, owner, building_id, condition) in metas {
+        let Some(spec) = buildings.get(building_id) else {
+            tracing::error!(?meta, "building {building_id:?} is missing from building data");
+            continue;
+        };
+        match &spec.building_type {
+            BuildingTypeSpec::Hq { .. } => hqs.entry(owner.0).or_default().push(meta.tile),
+            BuildingTypeSpec::Transportation { radius, .. } => {
+                let radius = condition.copied().unwrap_or_default().scale_radius(*radius);
+                if radius == 0 {
+                    continue;
+                }
+                transports
+                    .entry(owner.0)
+                    .or_default()
+                    .push((meta.tile, radius));
+            }
+            _ => (),
+        }
+    }
+
+    let mut connected = HashMap::with_capacity(hqs.len());
+    for (owner, hq_tiles) in hqs {
+        let mut unvisited = transports.remove(&owner).unwrap_or_default();
+        let mut tiles: HashSet<Hex> = hq_tiles.iter().copied().collect();
+
+        // Nodes are (tile, radius), with the HQ acting as a node with zero radius.
+        let mut frontier: Vec<(Hex, u32)> = hq_tiles.into_iter().map(|tile| (tile, 0)).collect();
+        while let Some((tile, radius)) = frontier.pop() {
+            unvisited.retain(|(other, other_radius)| {
+                if tile.unsigned_distance_to(*other) > radius + *other_radius {
+                    return true;
+                }
+                tiles.extend(other.range(*other_radius));
+                frontier.push((*other, *other_radius));
+                false
+            });
+        }
+
+        connected.insert(owner, tiles);
+    }
+
+    connected
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds [`BuildingCondition`] to tiles with transport buildings, and removes it from tiles without them.
+fn update_condition_on_build(
+    event: Trigger<OnInsert, BuildingId>,
+    mut c: Commands,
+    buildings: Res<BuildingData>,
+    metas: Query<&BuildingId>,
+)
+{
+    let entity = event.target();
+    let Ok(building_id) = metas.get(entity) else { return };
+    let is_transport = buildings
+        .get(building_id)
+        .is_some_and(|spec| matches!(spec.building_type, BuildingTypeSpec::Transportation { .. }));
+
+    if is_transport {
+        c.entity(entity).insert(BuildingCondition::default());
+    } else {
+        c.entity(entity).remove::<BuildingCondition>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn remove_condition_on_demolish(event: Trigger<OnRemove, BuildingId>, mut c: Commands)
+{
+    c.entity(event.target()).try_remove::<BuildingCondition>();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Maintains transport buildings at the end of a round.
+///
+/// Buildings are restored to full condition if their owner can pay the maintenance cost. Otherwise they decay by
+/// their disrepair rate. Buildings are maintained in tile order so payment is deterministic.
+pub(crate) fn maintain_transport(
+    buildings: Res<BuildingData>,
+    mut transports: Query<(&TileMeta, &TileOwner, &BuildingId, &mut BuildingCondition)>,
+    mut inventories: Query<&mut PlayerInventory>,
+)
+{
+    let mut transports: Vec<_> = transports.iter_mut().collect();
+    transports.sort_unstable_by_key(|(meta, owner, ..)| (owner.0, meta.tile.x, meta.tile.y));
+
+    for (meta, owner, building_id, mut condition) in transports {
+        let Some(BuildingTypeSpec::Transportation { disrepair_rate, maintenance_cost, .. }) =
+            buildings.get(building_id).map(|spec| &spec.building_type)
+        else {
+            tracing::error!(?meta, "building {building_id:?} is not a transport building");
+            continue;
+        };
+
+        let maintained = get_inventory_mut(&mut inventories, owner.0)
+            .is_some_and(|mut inventory| inventory.try_spend(maintenance_cost).is_ok());
+        let new_condition = match maintained {
+            true => 1.0,
+            false => (condition.0 - *disrepair_rate).max(0.0),
+        };
+        if condition.0 != new_condition {
+            condition.0 = new_condition;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct TransportPlugin;
+
+impl Plugin for TransportPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_observer(update_condition_on_build)
+            .add_observer(remove_condition_on_demolish)
+            .add_systems(RoundEnd, maintain_transport.after(produce_resources));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
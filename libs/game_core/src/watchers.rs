@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use bevy_replicon::shared::backend::connected_client::NetworkId;
+
+use crate::vis::*;
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gives watchers the [`Watcher`] attribute when they connect.
+///
+/// Watchers are game clients without player entities.
+fn handle_watcher_connect(
+    event: Trigger<OnAdd, NetworkId>,
+    ids: Query<&NetworkId>,
+    players: Option<Res<PlayerMap>>,
+    mut attributes: ClientAttributes,
+)
+{
+    let Ok(id) = ids.get(event.target()) else { return };
+    let client_id = id.get();
+    if players.is_some_and(|players| players.is_player(client_id)) {
+        return;
+    }
+
+    tracing::debug!("client {client_id} connected as a watcher");
+    attributes.add(client_id, Watcher);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Visibility attribute for clients watching the game.
+///
+/// Watchers can see the state of all players.
+#[derive(VisibilityAttribute, Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Watcher;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a visibility condition for state owned by a player, which is also visible to watchers.
+pub(crate) fn owner_visibility(client_id: ClientId) -> VisibilityCondition
+{
+    vis!(any!(Client(client_id), Watcher))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct WatcherPlugin;
+
+impl Plugin for WatcherPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_observer(handle_watcher_connect);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
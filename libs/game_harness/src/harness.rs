@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_girk_game_fw::*;
+use bevy_girk_wiring_server::*;
+use game_core::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of updates [`GameHarness::advance_to`] tolerates without game time advancing.
+const MAX_STALLED_UPDATES: usize = 16;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a game initializer with human players named after their client ids.
+pub fn make_initializer(
+    seed: u128,
+    duration_config: GameDurationConfig,
+    players: impl IntoIterator<Item = ClientId>,
+) -> ProvGameInitializer
+{
+    let players = players
+        .into_iter()
+        .map(|client_id| {
+            let state = PlayerState {
+                id: PlayerId { id: client_id },
+                name: PlayerName { name: format!("player{client_id}") },
+                ..Default::default()
+            };
+            (client_id, state)
+        })
+        .collect();
+
+    ProvGameInitializer {
+        game_context: GameContext { game_id: 0, seed, duration_config },
+        players,
+        bots: HashMap::default(),
+        user_ids: HashMap::default(),
+        snapshot: None,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Builds and drives a game app without networking.
+///
+/// App time advances by exactly one tick per update, so simulations are deterministic for a given
+/// [`ProvGameInitializer`], [`GameData`], and script.
+pub struct GameHarness
+{
+    app: App,
+    tick: Duration,
+    /// Requests that haven't been submitted yet, sorted by time.
+    script: Vec<ScriptedRequest>,
+    messages: Vec<CapturedGameMsg>,
+}
+
+impl GameHarness
+{
+    /// Makes a new harness.
+    ///
+    /// Game framework startup finishes on the first update, since no clients need to connect.
+    pub fn new(initializer: ProvGameInitializer, game_data: GameData, ticks_per_sec: u32) -> Self
+    {
+        let ticks_per_sec = ticks_per_sec.max(1);
+        let tick = Duration::from_secs(1) / ticks_per_sec;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+        prepare_game_app_framework(
+            &mut app,
+            GameFwClients::new(Default::default()),
+            GameFwConfig::new(ticks_per_sec, 1, 0),
+        );
+        prepare_game_app_replication(&mut app, Duration::from_millis(100));
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        app.add_plugins(GamePlugin)
+            .insert_resource(initializer)
+            .init_resource::<GameRecorder>();
+        game_data.insert(app.world_mut());
+        app.finish();
+        app.cleanup();
+
+        Self { app, tick, script: Vec::default(), messages: Vec::default() }
+    }
+
+    /// Adds a request to submit at the given game time.
+    ///
+    /// Requests scheduled for the same time are submitted in the order they were added.
+    pub fn schedule(&mut self, time: Duration, client_id: ClientId, request: ClientRequest)
+    {
+        let idx = self
+            .script
+            .partition_point(|scripted| scripted.time <= time);
+        self.script
+            .insert(idx, ScriptedRequest { time, client_id, request });
+    }
+
+    /// Adds all requests in a script.
+    pub fn schedule_all(&mut self, script: impl IntoIterator<Item = ScriptedRequest>)
+    {
+        for scripted in script {
+            self.schedule(scripted.time, scripted.client_id, scripted.request);
+        }
+    }
+
+    /// Submits due requests, then runs one app update.
+    pub fn update(&mut self)
+    {
+        let now = self.game_time();
+        let num_due = self.script.partition_point(|scripted| scripted.time <= now);
+        for scripted in self.script.drain(..num_due) {
+            tracing::trace!(?scripted, "submitting scripted request");
+            handle_client_request(self.app.world_mut(), scripted.client_id, scripted.request);
+        }
+
+        self.app.update();
+        self.collect_messages();
+    }
+
+    /// Runs enough updates to advance app time by the given duration (rounded up to whole ticks).
+    ///
+    /// Game time doesn't advance until the game framework leaves initialization.
+    pub fn advance(&mut self, duration: Duration)
+    {
+        let ticks = duration.as_nanos().div_ceil(self.tick.as_nanos());
+        for _ in 0..ticks {
+            self.update();
+        }
+    }
+
+    /// Runs updates until game time reaches `time`, shortening the last update so game time lands on it exactly.
+    ///
+    /// Stops early if the game ends or game time stops advancing (e.g. while the game framework is initializing
+    /// for longer than expected).
+    pub fn advance_to(&mut self, time: Duration)
+    {
+        let mut stalled = 0;
+        while self.game_time() < time && self.game_over_report().is_none() {
+            let prev = self.game_time();
+            let step = (time - prev).min(self.tick);
+            self.app
+                .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+            self.update();
+
+            if self.game_time() > prev {
+                stalled = 0;
+                continue;
+            }
+            stalled += 1;
+            if stalled > MAX_STALLED_UPDATES {
+                tracing::warn!(?time, ?prev, "game time stalled while advancing");
+                break;
+            }
+        }
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.tick));
+    }
+
+    /// Submits a request immediately, bypassing the script.
+    pub fn submit(&mut self, client_id: ClientId, request: ClientRequest)
+    {
+        handle_client_request(self.app.world_mut(), client_id, request);
+        self.collect_messages();
+    }
+
+    /// Runs updates until the game over report is available.
+    ///
+    /// Returns `None` if the game doesn't end within `max_duration` of app time.
+    pub fn run_to_end(&mut self, max_duration: Duration) -> Option<&ProvGameOverReport>
+    {
+        let max_ticks = max_duration.as_nanos().div_ceil(self.tick.as_nanos());
+        for _ in 0..max_ticks {
+            if self.game_over_report().is_some() {
+                break;
+            }
+            self.update();
+        }
+
+        self.game_over_report()
+    }
+
+    /// Elapsed game time.
+    pub fn game_time(&self) -> Duration
+    {
+        self.app.world().resource::<GameTime>().elapsed()
+    }
+
+    pub fn game_state(&self) -> GameState
+    {
+        **self.app.world().resource::<State<GameState>>()
+    }
+
+    /// Messages captured since the harness was created or the last call to [`Self::take_messages`].
+    pub fn messages(&self) -> &[CapturedGameMsg]
+    {
+        &self.messages
+    }
+
+    pub fn take_messages(&mut self) -> Vec<CapturedGameMsg>
+    {
+        std::mem::take(&mut self.messages)
+    }
+
+    pub fn game_over_report(&self) -> Option<&ProvGameOverReport>
+    {
+        self.app
+            .world()
+            .resource::<GameRecorder>()
+            .game_over_report()
+    }
+
+    pub fn world(&self) -> &World
+    {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World
+    {
+        self.app.world_mut()
+    }
+
+    fn collect_messages(&mut self)
+    {
+        let time = self.game_time();
+        let recorded = self
+            .app
+            .world_mut()
+            .resource_mut::<GameRecorder>()
+            .take_messages();
+        self.messages.extend(
+            recorded
+                .into_iter()
+                .map(|(target, msg)| CapturedGameMsg { time, target, msg }),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
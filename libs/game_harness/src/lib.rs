@@ -0,0 +1,7 @@
+//! Headless game simulation for testing gameplay rules without networking.
+
+mod harness;
+mod script;
+
+pub use harness::*;
+pub use script::*;
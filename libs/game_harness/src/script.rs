@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A client request that will be submitted once the game reaches a specific game time.
+#[derive(Debug, Clone)]
+pub struct ScriptedRequest
+{
+    /// Game time when the request should be submitted.
+    pub time: Duration,
+    pub client_id: ClientId,
+    pub request: ClientRequest,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A game message captured by the harness.
+#[derive(Debug, Clone)]
+pub struct CapturedGameMsg
+{
+    /// Game time when the message was sent.
+    pub time: Duration,
+    pub target: GameMsgTarget,
+    pub msg: GameMsg,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
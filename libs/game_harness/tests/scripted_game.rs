@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use game_core::*;
+use game_harness::*;
+use hexx::Hex;
+use utils::RootConfigs;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const TICKS_PER_SEC: u32 = 20;
+const TILE_SELECT_MS: u64 = 2000;
+const ROUND_MS: u64 = 1000;
+const NUM_ROUNDS: u32 = 3;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn test_game_data() -> GameData
+{
+    let config_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../config");
+    let configs = RootConfigs::new(&config_dir, &["game"]).unwrap();
+    let mut data = GameData::new(&configs).unwrap();
+    data.replay_settings.enabled = false;
+    data.snapshot_settings.enabled = false;
+    data
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_harness() -> GameHarness
+{
+    let duration_config = GameDurationConfig {
+        tile_select_duration_ms: TILE_SELECT_MS,
+        round_duration_ms: ROUND_MS,
+        num_rounds: NUM_ROUNDS,
+    };
+    let initializer = make_initializer(42, duration_config, [1, 2]);
+    GameHarness::new(initializer, test_game_data(), TICKS_PER_SEC)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn advance_to_state(harness: &mut GameHarness, state: GameState)
+{
+    for _ in 0..(TICKS_PER_SEC * 10) {
+        if harness.game_state() == state {
+            return;
+        }
+        harness.update();
+    }
+    panic!("game did not reach {state:?}, stuck in {:?}", harness.game_state());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn selectable_tile(harness: &mut GameHarness, client_id: ClientId) -> Hex
+{
+    let mut tiles: Vec<Hex> = harness
+        .world_mut()
+        .query::<(&TileMeta, &SelectableTile)>()
+        .iter(harness.world())
+        .filter(|(_, selectable)| selectable.client == client_id)
+        .map(|(meta, _)| meta.tile)
+        .collect();
+    tiles.sort_unstable_by_key(|tile| (tile.x, tile.y));
+    *tiles.first().expect("player should have selectable tiles")
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn owned_tiles(harness: &mut GameHarness, client_id: ClientId) -> HashSet<Hex>
+{
+    harness
+        .world_mut()
+        .query::<(&TileMeta, &TileOwner)>()
+        .iter(harness.world())
+        .filter(|(_, owner)| owner.0 == client_id)
+        .map(|(meta, _)| meta.tile)
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Finds an unowned ownable tile next to a player's territory.
+fn claimable_tile(harness: &mut GameHarness, client_id: ClientId) -> Hex
+{
+    let owned: HashSet<Hex> = harness
+        .world_mut()
+        .query_filtered::<&TileMeta, With<TileOwner>>()
+        .iter(harness.world())
+        .map(|meta| meta.tile)
+        .collect();
+    let territory = owned_tiles(harness, client_id);
+    let mut candidates: Vec<Hex> = harness
+        .world_mut()
+        .query_filtered::<&MapTile, With<OwnableTile>>()
+        .iter(harness.world())
+        .map(|tile| tile.0)
+        .filter(|tile| !owned.contains(tile))
+        .filter(|tile| {
+            tile.all_neighbors()
+                .iter()
+                .any(|neighbor| territory.contains(neighbor))
+        })
+        .collect();
+    candidates.sort_unstable_by_key(|tile| (tile.x, tile.y));
+    *candidates
+        .first()
+        .expect("player should have a claimable tile")
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn claim_results(messages: &[CapturedGameMsg], client_id: ClientId) -> Vec<(u32, Vec<TileClaimResult>)>
+{
+    messages
+        .iter()
+        .filter(|captured| matches!(captured.target, GameMsgTarget::Client(target) if target == client_id))
+        .filter_map(|captured| match &captured.msg {
+            GameMsg::TileClaimResults { round, results } => Some((*round, results.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn scripted_game_runs_to_game_over()
+{
+    let mut harness = make_harness();
+
+    // Tile select: each player gets an HQ and selects one extra tile.
+    advance_to_state(&mut harness, GameState::TileSelect);
+    assert_eq!(owned_tiles(&mut harness, 1).len(), 1);
+    let selected = selectable_tile(&mut harness, 1);
+    let select_time = harness.game_time() + Duration::from_millis(100);
+    harness.schedule(
+        select_time,
+        1,
+        ClientRequest::PlayerInput(PlayerInput::SelectTile(selected)),
+    );
+
+    // Play: claim a tile next to the player's territory in the first round.
+    advance_to_state(&mut harness, GameState::Play);
+    assert!(owned_tiles(&mut harness, 1).contains(&selected));
+    let claimed = claimable_tile(&mut harness, 1);
+    let claim_time = harness.game_time() + Duration::from_millis(100);
+    harness.schedule(
+        claim_time,
+        1,
+        ClientRequest::PlayerInput(PlayerInput::ClaimTile(claimed)),
+    );
+
+    // Round end: the uncontested claim is won.
+    harness.advance_to(Duration::from_millis(TILE_SELECT_MS + ROUND_MS + 100));
+    assert!(owned_tiles(&mut harness, 1).contains(&claimed));
+
+    let messages = harness.messages().to_vec();
+    assert!(
+        !messages
+            .iter()
+            .any(|captured| matches!(captured.msg, GameMsg::RequestRejected { .. })),
+        "no scripted request should be rejected"
+    );
+    let results = claim_results(&messages, 1);
+    assert_eq!(results.len(), 1);
+    let (round, results) = &results[0];
+    assert_eq!(*round, 1);
+    assert!(matches!(
+        results.as_slice(),
+        [TileClaimResult::Won { tile, owner: 1, .. }] if *tile == claimed
+    ));
+
+    // The other player is far away, so it isn't told about the claim.
+    assert!(claim_results(&messages, 2)
+        .iter()
+        .flat_map(|(_, results)| results.iter())
+        .all(|result| result.tile() != claimed));
+
+    // Game over.
+    let report = harness
+        .run_to_end(Duration::from_secs(30))
+        .expect("game should end")
+        .clone();
+    assert_eq!(harness.game_state(), GameState::End);
+    assert_eq!(report.game_id, 0);
+    assert_eq!(report.rounds, NUM_ROUNDS);
+    let mut reported: Vec<ClientId> = report
+        .player_reports
+        .iter()
+        .map(|player| player.client_id)
+        .collect();
+    reported.sort_unstable();
+    assert_eq!(reported, vec![1, 2]);
+
+    assert!(harness.messages().iter().any(|captured| matches!(
+        (&captured.target, &captured.msg),
+        (GameMsgTarget::All, GameMsg::CurrentGameState(GameState::End))
+    )));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
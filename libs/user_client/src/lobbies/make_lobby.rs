@@ -4,6 +4,7 @@ use bevy_girk_backend_public::{HostUserClient, UserToHostRequest};
 use bevy_girk_utils::ser_msg;
 use renet2_setup::ConnectionType;
 use wiring_backend::{ProvLobbyConfig, ProvLobbyContents, ProvLobbyMemberType};
+use wiring_game_instance::GameRuleOverrides;
 
 use crate::*;
 
@@ -23,6 +24,7 @@ fn single_player_lobby(owner_id: u128, data: &MakeLobbyData) -> ProvLobbyContent
         owner_id,
         config: data.config.clone(),
         players: vec![(ConnectionType::Memory, owner_id)], // Must use memory connection type
+        watchers: Vec::default(),
     }
 }
 
@@ -131,7 +133,12 @@ impl Default for MakeLobbyData
         Self {
             member_type: ProvLobbyMemberType::Player,
             pwd: String::default(),
-            config: ProvLobbyConfig { max_players: 1 },
+            config: ProvLobbyConfig {
+                max_players: 1,
+                max_watchers: 0,
+                bots: Vec::default(),
+                rules: GameRuleOverrides::default(),
+            },
         }
     }
 }
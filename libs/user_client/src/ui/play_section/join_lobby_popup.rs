@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
+use wiring_backend::ProvLobbyMemberType;
 
 use crate::*;
 
@@ -79,8 +80,25 @@ pub(super) fn build_join_lobby_popup(_: &ActivateJoinLobbyPopup, h: &mut UiScene
     h.edit("content::grid::password_field", |_| {
         // does nothing yet
     });
-    h.edit("content::grid::join_as_field", |h| {
-        h.get("text").update_text("Player");
+    h.edit("content::grid::join_as_field::toggle_button", |h| {
+        h.get("text").update_on(
+            resource_mutation::<JoinLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<JoinLobbyData>| {
+                write_text!(e, *id, "{}", member_type_name(data.member_type));
+            },
+        );
+        h.on_pressed(|mut c: Commands, mut data: ReactResMut<JoinLobbyData>| {
+            let data = data.get_mut(&mut c);
+            data.member_type = toggle_member_type(data.member_type);
+        })
+        .enable_if(
+            resource_mutation::<JoinLobbyData>(),
+            |_: TargetId, data: ReactRes<JoinLobbyData>| {
+                data.contents
+                    .as_ref()
+                    .is_some_and(|contents| contents.max(ProvLobbyMemberType::Watcher) > 0)
+            },
+        );
     });
 
     // Popup buttons
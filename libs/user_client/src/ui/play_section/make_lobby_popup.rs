@@ -1,12 +1,91 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
-use wiring_backend::MAX_LOBBY_PLAYERS;
+use game_core::BotDifficulty;
+use wiring_backend::{ProvLobbyMemberType, GAME_RULE_BOUNDS, MAX_LOBBY_PLAYERS, MAX_LOBBY_WATCHERS};
+use wiring_game_instance::GameRuleOverrides;
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+pub(crate) fn member_type_name(member_type: ProvLobbyMemberType) -> &'static str
+{
+    match member_type {
+        ProvLobbyMemberType::Player => "Player",
+        ProvLobbyMemberType::Watcher => "Watcher",
+    }
+}
+
+pub(crate) fn toggle_member_type(member_type: ProvLobbyMemberType) -> ProvLobbyMemberType
+{
+    match member_type {
+        ProvLobbyMemberType::Player => ProvLobbyMemberType::Watcher,
+        ProvLobbyMemberType::Watcher => ProvLobbyMemberType::Player,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const MAP_DIMENSION_STEP: i32 = 5;
+const NUM_ROUNDS_STEP: u32 = 5;
+const DURATION_STEP_MS: u64 = 5_000;
+
+fn rule_text<T: std::fmt::Display>(value: Option<T>, units: &str) -> String
+{
+    match value {
+        Some(value) => format!("{value}{units}"),
+        None => "Default".into(),
+    }
+}
+
+/// Sets up a game rule field.
+///
+/// Rules start unset so the game config is used. Stepping down from the minimum unsets the rule.
+fn edit_rule_field(
+    h: &mut UiSceneHandle,
+    field: &str,
+    text: fn(&GameRuleOverrides) -> String,
+    step_up: fn(&mut GameRuleOverrides),
+    step_down: fn(&mut GameRuleOverrides),
+)
+{
+    h.edit(field, |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            move |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}", text(&data.config.rules));
+            },
+        );
+        h.get("buttons::add_button")
+            .on_pressed(move |mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                step_up(&mut data.get_mut(&mut c).config.rules);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                move |_: TargetId, data: ReactRes<MakeLobbyData>| {
+                    let mut rules = data.config.rules;
+                    step_up(&mut rules);
+                    rules != data.config.rules
+                },
+            );
+        h.get("buttons::remove_button")
+            .on_pressed(move |mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                step_down(&mut data.get_mut(&mut c).config.rules);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                move |_: TargetId, data: ReactRes<MakeLobbyData>| {
+                    let mut rules = data.config.rules;
+                    step_down(&mut rules);
+                    rules != data.config.rules
+                },
+            );
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(super) fn build_make_lobby_popup(_: &ActivateMakeLobbyPopup, h: &mut UiSceneHandle)
 {
     tracing::trace!("building make lobby popup");
@@ -65,17 +144,151 @@ pub(super) fn build_make_lobby_popup(_: &ActivateMakeLobbyPopup, h: &mut UiScene
             );
         h.get("buttons::remove_player_button")
             .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
-                let max = data.config.max_players;
-                data.get_mut(&mut c).config.max_players = max.saturating_sub(1).max(1);
+                let data = data.get_mut(&mut c);
+                let min = 1 + data.config.bots.len() as u16;
+                data.config.max_players = data.config.max_players.saturating_sub(1).max(min);
             })
             .enable_if(
                 resource_mutation::<MakeLobbyData>(),
-                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_players > 1,
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_human_players() > 1,
             );
     });
-    h.edit("content::grid::join_as_field", |h| {
-        h.get("text").update_text("Player");
+    h.edit("content::grid::bots_field", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}", data.config.bots.len());
+            },
+        );
+        // Bots fill player slots, so at least one slot is kept for the lobby owner.
+        h.get("buttons::add_bot_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                data.get_mut(&mut c)
+                    .config
+                    .add_bot(BotDifficulty::default());
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.can_add_bot(),
+            );
+        h.get("buttons::remove_bot_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                data.get_mut(&mut c).config.remove_bot();
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| !data.config.bots.is_empty(),
+            );
+    });
+    h.edit("content::grid::max_watchers_field", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}", data.config.max_watchers);
+            },
+        );
+        h.get("buttons::add_watcher_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let data = data.get_mut(&mut c);
+                data.config.max_watchers += 1;
+                data.config.max_watchers = data.config.max_watchers.min(MAX_LOBBY_WATCHERS);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_watchers < MAX_LOBBY_WATCHERS,
+            );
+        // The lobby owner can't watch a lobby with no watcher slots.
+        h.get("buttons::remove_watcher_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let data = data.get_mut(&mut c);
+                data.config.max_watchers = data.config.max_watchers.saturating_sub(1);
+                if data.config.max_watchers == 0 {
+                    data.member_type = ProvLobbyMemberType::Player;
+                }
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_watchers > 0,
+            );
     });
+    h.edit("content::grid::join_as_field::toggle_button", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}", member_type_name(data.member_type));
+            },
+        );
+        h.on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+            let data = data.get_mut(&mut c);
+            data.member_type = toggle_member_type(data.member_type);
+        })
+        .enable_if(
+            resource_mutation::<MakeLobbyData>(),
+            |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_watchers > 0,
+        );
+    });
+
+    // Game rules
+    edit_rule_field(
+        &mut h,
+        "content::grid::map_size_field",
+        |rules| rule_text(rules.map_dimension, ""),
+        |rules| {
+            rules.map_dimension = GAME_RULE_BOUNDS
+                .map_dimension
+                .step_up(rules.map_dimension, MAP_DIMENSION_STEP)
+        },
+        |rules| {
+            rules.map_dimension = GAME_RULE_BOUNDS
+                .map_dimension
+                .step_down(rules.map_dimension, MAP_DIMENSION_STEP)
+        },
+    );
+    edit_rule_field(
+        &mut h,
+        "content::grid::num_rounds_field",
+        |rules| rule_text(rules.num_rounds, ""),
+        |rules| {
+            rules.num_rounds = GAME_RULE_BOUNDS
+                .num_rounds
+                .step_up(rules.num_rounds, NUM_ROUNDS_STEP)
+        },
+        |rules| {
+            rules.num_rounds = GAME_RULE_BOUNDS
+                .num_rounds
+                .step_down(rules.num_rounds, NUM_ROUNDS_STEP)
+        },
+    );
+    edit_rule_field(
+        &mut h,
+        "content::grid::round_duration_field",
+        |rules| rule_text(rules.round_duration_ms.map(|ms| ms / 1000), "s"),
+        |rules| {
+            rules.round_duration_ms = GAME_RULE_BOUNDS
+                .round_duration_ms
+                .step_up(rules.round_duration_ms, DURATION_STEP_MS)
+        },
+        |rules| {
+            rules.round_duration_ms = GAME_RULE_BOUNDS
+                .round_duration_ms
+                .step_down(rules.round_duration_ms, DURATION_STEP_MS)
+        },
+    );
+    edit_rule_field(
+        &mut h,
+        "content::grid::tile_select_duration_field",
+        |rules| rule_text(rules.tile_select_duration_ms.map(|ms| ms / 1000), "s"),
+        |rules| {
+            rules.tile_select_duration_ms = GAME_RULE_BOUNDS
+                .tile_select_duration_ms
+                .step_up(rules.tile_select_duration_ms, DURATION_STEP_MS)
+        },
+        |rules| {
+            rules.tile_select_duration_ms = GAME_RULE_BOUNDS
+                .tile_select_duration_ms
+                .step_down(rules.tile_select_duration_ms, DURATION_STEP_MS)
+        },
+    );
 
     // Info text
     h.get("content::connection_notice::text").update_on(
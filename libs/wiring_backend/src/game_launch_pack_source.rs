@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 
 use bevy_girk_backend_public::*;
 use bevy_girk_game_instance::*;
+use game_core::BotDifficulty;
 #[cfg(not(target_family = "wasm"))]
 use rand::seq::SliceRandom;
 #[cfg(not(target_family = "wasm"))]
@@ -23,6 +24,30 @@ fn make_player_init_data(connection: ConnectionType, user_id: u128, client_id: C
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn make_watcher_init_data(connection: ConnectionType, user_id: u128, client_id: ClientId) -> ClientGameInit
+{
+    let client_type = ClientTypeInfo::Watcher;
+
+    ClientGameInit { connection, user_id, client_id, client_type }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_bot_init_data(difficulty: BotDifficulty, client_id: ClientId) -> ClientGameInit
+{
+    // Bots don't connect to the game, so the connection type and user id are placeholders.
+    let client_type = ClientTypeInfo::Bot { difficulty };
+
+    ClientGameInit {
+        connection: ConnectionType::Memory,
+        user_id: 0u128,
+        client_id,
+        client_type,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn launch_pack_from_req(
     game_factory_config: &ProvGameFactoryConfig,
     start_request: &GameStartRequest,
@@ -34,23 +59,41 @@ fn launch_pack_from_req(
         return Err(());
     };
 
-    get_launch_pack(game_factory_config.clone(), lobby_contents)
+    // Hosted games save snapshots so the game hub can relaunch them if their instance dies.
+    let (game_id, mut data) = get_launch_data(game_factory_config.clone(), lobby_contents)?;
+    data.snapshots = true;
+    Ok(GameLaunchPack::new(game_id, data))
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Makes a launch pack for a new game that doesn't save snapshots (e.g. local-player games).
 pub fn get_launch_pack(
     game_factory_config: ProvGameFactoryConfig,
-    #[allow(unused_mut)] mut lobby_contents: ProvLobbyContents,
+    lobby_contents: ProvLobbyContents,
 ) -> Result<GameLaunchPack, ()>
+{
+    let (game_id, data) = get_launch_data(game_factory_config, lobby_contents)?;
+    Ok(GameLaunchPack::new(game_id, data))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn get_launch_data(
+    game_factory_config: ProvGameFactoryConfig,
+    #[allow(unused_mut)] mut lobby_contents: ProvLobbyContents,
+) -> Result<(u64, LaunchData), ()>
 {
     // extract players/watchers from lobby contents
     let num_players = lobby_contents.players.len();
+    let num_bots = lobby_contents.bots().len();
+    let num_watchers = lobby_contents.watchers.len();
 
     // shuffle the game participants
+    // - bots run inside the game app, so they are allowed in single-player games
     #[cfg(target_family = "wasm")]
     {
-        if num_players != 1 {
+        if num_players != 1 || num_watchers != 0 {
             panic!("only single-player game instances are allowed on WASM");
         }
     }
@@ -60,16 +103,32 @@ pub fn get_launch_pack(
     }
 
     // make init data for the clients
-    let mut client_init_data = Vec::with_capacity(num_players);
+    // - watchers get client ids after players and bots
+    let mut client_init_data = Vec::with_capacity(num_players + num_bots + num_watchers);
 
     for (idx, (connection, player_user_id)) in lobby_contents.players.iter().enumerate() {
         let client_id = idx as u64;
         client_init_data.push(make_player_init_data(*connection, *player_user_id, client_id));
     }
+    for (idx, difficulty) in lobby_contents.bots().iter().enumerate() {
+        let client_id = (num_players + idx) as u64;
+        client_init_data.push(make_bot_init_data(*difficulty, client_id));
+    }
+    for (idx, (connection, watcher_user_id)) in lobby_contents.watchers.iter().enumerate() {
+        let client_id = (num_players + num_bots + idx) as u64;
+        client_init_data.push(make_watcher_init_data(*connection, *watcher_user_id, client_id));
+    }
 
-    // launch pack
-    let data = LaunchData { config: game_factory_config, clients: client_init_data };
-    Ok(GameLaunchPack::new(lobby_contents.id, data))
+    // launch data
+    // - new games never resume, the game hub sets `resume` when relaunching a game whose instance died
+    let data = LaunchData {
+        config: game_factory_config,
+        clients: client_init_data,
+        resume: false,
+        snapshots: false,
+        rules: lobby_contents.config.rules,
+    };
+    Ok((lobby_contents.id, data))
 }
 
 //-------------------------------------------------------------------------------------------------------------------
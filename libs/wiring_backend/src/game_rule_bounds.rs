@@ -0,0 +1,118 @@
+use std::ops::{Add, Sub};
+
+use serde::{Deserialize, Serialize};
+use wiring_game_instance::GameRuleOverrides;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inclusive range of values allowed for a game rule.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct RuleBounds<T>
+{
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> RuleBounds<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    /// Checks if an override is within bounds. Unset overrides are always allowed.
+    pub fn allows(&self, value: Option<T>) -> bool
+    {
+        value.is_none_or(|value| self.min <= value && value <= self.max)
+    }
+
+    /// Steps an override up. Unset overrides step to the minimum.
+    pub fn step_up(&self, value: Option<T>, step: T) -> Option<T>
+    {
+        match value {
+            None => Some(self.min),
+            Some(value) if value + step > self.max => Some(self.max),
+            Some(value) => Some(value + step),
+        }
+    }
+
+    /// Steps an override down. Overrides at the minimum are unset.
+    pub fn step_down(&self, value: Option<T>, step: T) -> Option<T>
+    {
+        match value {
+            None => None,
+            Some(value) if value <= self.min => None,
+            Some(value) if value < self.min + step => Some(self.min),
+            Some(value) => Some(value - step),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Bounds on the game rules a lobby can override.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRuleBounds
+{
+    pub map_dimension: RuleBounds<i32>,
+    pub num_rounds: RuleBounds<u32>,
+    pub round_duration_ms: RuleBounds<u64>,
+    pub tile_select_duration_ms: RuleBounds<u64>,
+}
+
+impl GameRuleBounds
+{
+    /// Checks if all rule overrides are within bounds.
+    pub fn allows(&self, rules: &GameRuleOverrides) -> bool
+    {
+        self.map_dimension.allows(rules.map_dimension)
+            && self.num_rounds.allows(rules.num_rounds)
+            && self.round_duration_ms.allows(rules.round_duration_ms)
+            && self
+                .tile_select_duration_ms
+                .allows(rules.tile_select_duration_ms)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const BOUNDS: RuleBounds<u32> = RuleBounds { min: 10, max: 30 };
+
+    #[test]
+    fn step_up_starts_at_min_and_clamps_to_max()
+    {
+        assert_eq!(BOUNDS.step_up(None, 5), Some(10));
+        assert_eq!(BOUNDS.step_up(Some(10), 5), Some(15));
+        assert_eq!(BOUNDS.step_up(Some(28), 5), Some(30));
+        assert_eq!(BOUNDS.step_up(Some(30), 5), Some(30));
+    }
+
+    #[test]
+    fn step_down_clamps_to_min_then_unsets()
+    {
+        assert_eq!(BOUNDS.step_down(None, 5), None);
+        assert_eq!(BOUNDS.step_down(Some(30), 5), Some(25));
+        assert_eq!(BOUNDS.step_down(Some(12), 5), Some(10));
+        assert_eq!(BOUNDS.step_down(Some(10), 5), None);
+    }
+
+    #[test]
+    fn steps_stay_within_bounds()
+    {
+        let mut value = None;
+        for _ in 0..10 {
+            value = BOUNDS.step_up(value, 7);
+            assert!(BOUNDS.allows(value));
+        }
+        assert_eq!(value, Some(30));
+        for _ in 0..10 {
+            value = BOUNDS.step_down(value, 7);
+            assert!(BOUNDS.allows(value));
+        }
+        assert_eq!(value, None);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,5 @@
+use crate::*;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 //todo: add all game-related server configs here for common reference
@@ -6,7 +8,14 @@ pub const LOBBY_LIST_SIZE: usize = 10;
 pub const ACK_TIMEOUT_MILLIS: u64 = 14_000;
 
 pub const MAX_LOBBY_PLAYERS: u16 = 4;
-// pub const MAX_LOBBY_WATCHERS: u16 = 4;
+pub const MAX_LOBBY_WATCHERS: u16 = 4;
 pub const MIN_PLAYERS_TO_LAUNCH: u16 = 1;
 
+pub const GAME_RULE_BOUNDS: GameRuleBounds = GameRuleBounds {
+    map_dimension: RuleBounds { min: 25, max: 60 },
+    num_rounds: RuleBounds { min: 10, max: 120 },
+    round_duration_ms: RuleBounds { min: 5_000, max: 60_000 },
+    tile_select_duration_ms: RuleBounds { min: 10_000, max: 60_000 },
+};
+
 //-------------------------------------------------------------------------------------------------------------------
@@ -1,9 +1,11 @@
 mod game_launch_pack_source;
+mod game_rule_bounds;
 mod host_client_config;
 mod lobby_checker;
 mod lobby_contents;
 
 pub use game_launch_pack_source::*;
+pub use game_rule_bounds::*;
 pub use host_client_config::*;
 pub use lobby_checker::*;
 pub use lobby_contents::*;
@@ -10,22 +10,29 @@ pub struct ProvLobbyChecker
 {
     /// Max number of players allowed in a lobby.
     pub max_lobby_players: u16,
+    /// Max number of watchers allowed in a lobby.
+    pub max_lobby_watchers: u16,
     /// Min number of players in a lobby required to launch a lobby.
     pub min_players_to_launch: u16,
+    /// Bounds on the game rules a lobby can override.
+    pub game_rule_bounds: GameRuleBounds,
 }
 
 impl ProvLobbyChecker
 {
-    pub fn count_members(lobby_data: &LobbyData) -> Result<usize, String>
+    /// Counts the players and watchers in a lobby.
+    pub fn count_members(lobby_data: &LobbyData) -> Result<(usize, usize), String>
     {
         let mut num_players = 0;
+        let mut num_watchers = 0;
         for member_data in lobby_data.members.iter().map(|(_, color)| color) {
             match ProvLobbyMemberType::try_from(member_data.color)? {
                 ProvLobbyMemberType::Player => num_players += 1,
+                ProvLobbyMemberType::Watcher => num_watchers += 1,
             }
         }
 
-        Ok(num_players)
+        Ok((num_players, num_watchers))
     }
 
     /// Check if the lobby may be hosted by a server.
@@ -57,22 +64,29 @@ impl LobbyChecker for ProvLobbyChecker
         if config.max_players > self.max_lobby_players {
             return false;
         }
-        // if config.max_watchers > self.max_lobby_watchers {
-        //     return false;
-        // }
+        // at least one slot must be available for a human player
+        if config.max_human_players() == 0 {
+            return false;
+        }
+        if config.max_watchers > self.max_lobby_watchers {
+            return false;
+        }
+        if !self.game_rule_bounds.allows(&config.rules) {
+            return false;
+        }
 
         // get max count member types
-        let Ok(num_players) = Self::count_members(&lobby.data) else {
+        let Ok((num_players, num_watchers)) = Self::count_members(&lobby.data) else {
             return false;
         };
 
         // check configs
-        if num_players > config.max_players as usize {
+        if num_players > config.max_human_players() as usize {
+            return false;
+        }
+        if num_watchers > config.max_watchers as usize {
             return false;
         }
-        // if num_watchers > config.max_watchers as usize {
-        //     return false;
-        // }
 
         true
     }
@@ -102,7 +116,7 @@ impl LobbyChecker for ProvLobbyChecker
         };
 
         // count current players and watchers
-        let Ok(num_players) = Self::count_members(&lobby.data) else {
+        let Ok((num_players, num_watchers)) = Self::count_members(&lobby.data) else {
             return false;
         };
 
@@ -113,14 +127,15 @@ impl LobbyChecker for ProvLobbyChecker
 
         match member_type {
             ProvLobbyMemberType::Player => {
-                if num_players >= config.max_players as usize {
+                if num_players >= config.max_human_players() as usize {
                     return false;
                 }
-            } /* ProvLobbyMemberType::Watcher => {
-               *     if num_watchers >= config.max_watchers as usize {
-               *         return false;
-               *     }
-               * } */
+            }
+            ProvLobbyMemberType::Watcher => {
+                if num_watchers >= config.max_watchers as usize {
+                    return false;
+                }
+            }
         }
 
         true
@@ -130,11 +145,15 @@ impl LobbyChecker for ProvLobbyChecker
     fn can_launch(&self, lobby: &Lobby) -> bool
     {
         // count players
-        let Ok(num_players) = Self::count_members(&lobby.data) else {
+        // - watchers don't count toward the players needed to launch
+        let Ok((num_players, _)) = Self::count_members(&lobby.data) else {
+            return false;
+        };
+        let Some(config) = deser_msg::<ProvLobbyConfig>(&lobby.custom_data()) else {
             return false;
         };
 
-        Self::can_launch_hosted(num_players, self.min_players_to_launch as usize)
+        Self::can_launch_hosted(num_players + config.bots.len(), self.min_players_to_launch as usize)
     }
 }
 
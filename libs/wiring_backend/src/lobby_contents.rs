@@ -1,7 +1,9 @@
 use bevy_girk_backend_public::*;
 use bevy_girk_utils::*;
+use game_core::BotDifficulty;
 use renet2_setup::ConnectionType;
 use serde::{Deserialize, Serialize};
+use wiring_game_instance::GameRuleOverrides;
 
 use crate::*;
 
@@ -10,18 +12,51 @@ use crate::*;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProvLobbyConfig
 {
-    /// Max players allowed in the lobby.
+    /// Max players allowed in the lobby, including bots.
     pub max_players: u16,
-    // Max watchers allowed in the lobby.
-    // pub max_watchers: u16,
+    /// Max watchers allowed in the lobby.
+    pub max_watchers: u16,
+    /// Bots that will play in the lobby's game. Bots take up player slots.
+    pub bots: Vec<BotDifficulty>,
+    /// Game rules that replace the values in the game config.
+    pub rules: GameRuleOverrides,
 }
 
 impl ProvLobbyConfig
 {
+    /// Number of player slots available to human players.
+    pub fn max_human_players(&self) -> u16
+    {
+        self.max_players.saturating_sub(self.bots.len() as u16)
+    }
+
+    /// Checks if a bot can be added. At least one player slot is always kept for a human player.
+    pub fn can_add_bot(&self) -> bool
+    {
+        self.max_human_players() > 1
+    }
+
+    /// Adds a bot that takes up a player slot.
+    ///
+    /// Returns false if there are no open player slots (see [`Self::can_add_bot`]).
+    pub fn add_bot(&mut self, difficulty: BotDifficulty) -> bool
+    {
+        if !self.can_add_bot() {
+            return false;
+        }
+        self.bots.push(difficulty);
+        true
+    }
+
+    /// Removes the most recently added bot.
+    pub fn remove_bot(&mut self) -> Option<BotDifficulty>
+    {
+        self.bots.pop()
+    }
+
     pub fn is_single_player(&self) -> bool
     {
-        // self.max_players == 1 && self.max_watchers == 0
-        self.max_players == 1
+        self.max_human_players() == 1 && self.max_watchers == 0
     }
 }
 
@@ -31,7 +66,7 @@ impl ProvLobbyConfig
 pub enum ProvLobbyMemberType
 {
     Player,
-    //Watcher,
+    Watcher,
 }
 
 impl TryFrom<LobbyMemberColor> for ProvLobbyMemberType
@@ -42,7 +77,7 @@ impl TryFrom<LobbyMemberColor> for ProvLobbyMemberType
     {
         match color.0 {
             0u64 => Ok(ProvLobbyMemberType::Player),
-            //1u64 => Ok(ProvLobbyMemberType::Watcher),
+            1u64 => Ok(ProvLobbyMemberType::Watcher),
             _ => Err(format!("failed converting {color:?} to ProvLobbyMemberType")),
         }
     }
@@ -54,7 +89,7 @@ impl Into<LobbyMemberColor> for ProvLobbyMemberType
     {
         match self {
             ProvLobbyMemberType::Player => LobbyMemberColor(0u64),
-            //ProvLobbyMemberType::Watcher => LobbyMemberColor(1u64),
+            ProvLobbyMemberType::Watcher => LobbyMemberColor(1u64),
         }
     }
 }
@@ -77,8 +112,8 @@ pub struct ProvLobbyContents
 
     /// Players in this lobby.
     pub players: Vec<(ConnectionType, u128)>,
-    // Watchers in this lobby.
-    // pub watchers: Vec<(ConnectionType, u128)>,
+    /// Watchers in this lobby.
+    pub watchers: Vec<(ConnectionType, u128)>,
 }
 
 impl ProvLobbyContents
@@ -87,7 +122,7 @@ impl ProvLobbyContents
     {
         match member_type {
             ProvLobbyMemberType::Player => self.players.get(idx).map(|(_, id)| id),
-            // ProvLobbyMemberType::Watcher => self.watchers.get(idx).map(|(_, id)| id),
+            ProvLobbyMemberType::Watcher => self.watchers.get(idx).map(|(_, id)| id),
         }
     }
 
@@ -95,24 +130,32 @@ impl ProvLobbyContents
     {
         match member_type {
             ProvLobbyMemberType::Player => self.players.len(),
-            // ProvLobbyMemberType::Watcher => self.watchers.len(),
+            ProvLobbyMemberType::Watcher => self.watchers.len(),
         }
     }
 
     pub fn max(&self, member_type: ProvLobbyMemberType) -> u16
     {
         match member_type {
-            ProvLobbyMemberType::Player => self.config.max_players,
-            // ProvLobbyMemberType::Watcher => self.config.max_watchers,
+            ProvLobbyMemberType::Player => self.config.max_human_players(),
+            ProvLobbyMemberType::Watcher => self.config.max_watchers,
         }
     }
 
+    pub fn bots(&self) -> &[BotDifficulty]
+    {
+        &self.config.bots
+    }
+
     /// Check if the game can be launched while hosted by a server.
     ///
     /// This can be used to indicate to a user if a lobby is ready to launch.
     pub fn can_launch_hosted(&self) -> bool
     {
-        ProvLobbyChecker::can_launch_hosted(self.players.len(), MIN_PLAYERS_TO_LAUNCH as usize)
+        ProvLobbyChecker::can_launch_hosted(
+            self.players.len() + self.config.bots.len(),
+            MIN_PLAYERS_TO_LAUNCH as usize,
+        )
     }
 }
 
@@ -128,11 +171,11 @@ impl TryFrom<LobbyData> for ProvLobbyContents
 
         // members
         let mut players = Vec::default();
-        // let mut watchers = Vec::default();
+        let mut watchers = Vec::default();
         for (user_id, member_data) in data.members.iter() {
             match ProvLobbyMemberType::try_from(member_data.color)? {
                 ProvLobbyMemberType::Player => players.push((member_data.connection, *user_id)),
-                // ProvLobbyMemberType::Watcher => watchers.push((member_data.connection, *user_id)),
+                ProvLobbyMemberType::Watcher => watchers.push((member_data.connection, *user_id)),
             }
         }
 
@@ -141,7 +184,7 @@ impl TryFrom<LobbyData> for ProvLobbyContents
             owner_id: data.owner_id,
             config,
             players,
-            // watchers,
+            watchers,
         })
     }
 }
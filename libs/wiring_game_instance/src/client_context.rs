@@ -8,8 +8,8 @@ use serde::{Deserialize, Serialize};
 pub enum ClientType
 {
     Player,
-    // Currently not supported.
-    //Watcher,
+    /// Watchers can see the state of all players but can't send player inputs.
+    Watcher,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -27,6 +27,10 @@ pub struct ClientContext
     pub duration_config: GameDurationConfig,
     /// PRNG for generating the map deterministically.
     pub map_gen_prng: u64,
+    /// Map dimension used to generate the map, which may be overridden by the lobby.
+    ///
+    /// Replaces [`MapGenSettings::map_dimension`] on the client.
+    pub map_dimension: i32,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
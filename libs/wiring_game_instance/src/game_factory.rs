@@ -33,26 +33,39 @@ fn prepare_game_startup(
     config: &GameFwConfig,
     client_init_data: Vec<ClientGameInit>,
     duration_config: GameDurationConfig,
+    map_dimension: i32,
+    snapshot: Option<GameSnapshot>,
 ) -> Result<GameStartupHelper, String>
 {
-    let seed = {
-        // Seed is only needed on WASM when making a local-player game, so using the system time is harmless.
-        #[cfg(target_family = "wasm")]
-        {
-            wasm_timer::SystemTime::now()
-                .duration_since(wasm_timer::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_nanos()
-        }
+    // resumed games keep their original seed and duration config
+    let duration_config = snapshot
+        .as_ref()
+        .map(|snapshot| snapshot.duration_config)
+        .unwrap_or(duration_config);
+    let seed = snapshot
+        .as_ref()
+        .map(|snapshot| snapshot.seed)
+        .unwrap_or_else(|| {
+            // Seed is only needed on WASM when making a local-player game, so using the system time is harmless.
+            #[cfg(target_family = "wasm")]
+            {
+                wasm_timer::SystemTime::now()
+                    .duration_since(wasm_timer::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos()
+            }
 
-        #[cfg(not(target_family = "wasm"))]
-        bevy_girk_utils::gen_rand128()
-    };
+            #[cfg(not(target_family = "wasm"))]
+            bevy_girk_utils::gen_rand128()
+        });
     let map_gen_prng = map_gen_prng(seed);
 
     // prepare each client
     let mut client_set = HashSet::with_capacity(client_init_data.len());
     let mut players = HashMap::with_capacity(client_init_data.len());
+    let mut bots = HashMap::default();
+    let mut user_ids = HashMap::default();
+    let mut num_watchers = 0;
     let mut start_infos = Vec::with_capacity(client_init_data.len());
     let mut client_counts = ClientCounts::default();
 
@@ -70,12 +83,40 @@ fn prepare_game_startup(
                         ..Default::default()
                     },
                 );
+                user_ids.insert(client_id, client_init.user_id);
                 ClientInitializer {
                     context: ClientContext {
                         client_id,
                         client_type: ClientType::Player,
                         duration_config,
                         map_gen_prng,
+                        map_dimension,
+                    },
+                }
+            }
+            // Bots run inside the game app, so they aren't registered as game clients.
+            ClientTypeInfo::Bot { difficulty } => {
+                players.insert(
+                    client_id,
+                    PlayerState {
+                        id: PlayerId { id: client_id },
+                        name: PlayerName { name: format!("bot{client_id}") },
+                        ..Default::default()
+                    },
+                );
+                bots.insert(client_id, difficulty);
+                continue;
+            }
+            // Watchers are game clients, but they don't have player entities.
+            ClientTypeInfo::Watcher => {
+                num_watchers += 1;
+                ClientInitializer {
+                    context: ClientContext {
+                        client_id,
+                        client_type: ClientType::Watcher,
+                        duration_config,
+                        map_gen_prng,
+                        map_dimension,
                     },
                 }
             }
@@ -94,13 +135,14 @@ fn prepare_game_startup(
         start_infos.push(start_info)
     }
     debug_assert_eq!(client_set.len(), start_infos.len());
+    debug_assert_eq!(client_set.len() + bots.len(), players.len() + num_watchers);
 
     // finalize
     let game_context = GameContext { game_id, seed, duration_config };
 
     Ok(GameStartupHelper {
         client_set: GameFwClients::new(client_set),
-        prov_init: ProvGameInitializer { game_context, players },
+        prov_init: ProvGameInitializer { game_context, players, bots, user_ids, snapshot },
         start_infos,
         client_counts,
     })
@@ -108,6 +150,82 @@ fn prepare_game_startup(
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Gives the clients of a resumed game the client ids they had in the snapshot.
+///
+/// Human players are matched by user id, and bots are matched in client id order. Watchers aren't saved in
+/// snapshots, so they keep their client ids (which come after all players).
+#[cfg(not(target_family = "wasm"))]
+fn remap_resumed_clients(clients: &mut [ClientGameInit], snapshot: &GameSnapshot) -> Result<(), String>
+{
+    let num_players = clients
+        .iter()
+        .filter(|client| !matches!(client.client_type, ClientTypeInfo::Watcher))
+        .count();
+    if num_players != snapshot.players.len() {
+        return Err(format!(
+            "game has {num_players} players but the snapshot has {}",
+            snapshot.players.len()
+        ));
+    }
+
+    let mut snapshot_bots = snapshot
+        .players
+        .iter()
+        .filter(|player| player.bot.is_some());
+    for client in clients.iter_mut() {
+        let player = match client.client_type {
+            ClientTypeInfo::Player { .. } => snapshot
+                .players
+                .iter()
+                .find(|player| player.bot.is_none() && player.user_id == client.user_id),
+            ClientTypeInfo::Bot { .. } => snapshot_bots.next(),
+            ClientTypeInfo::Watcher => continue,
+        };
+        let Some(player) = player else {
+            return Err(format!("client {} (user {}) is not in the snapshot", client.client_id, client.user_id));
+        };
+
+        client.client_id = player.client_id;
+        client.client_type = match player.bot {
+            Some(difficulty) => ClientTypeInfo::Bot { difficulty },
+            None => ClientTypeInfo::Player { player_name: player.name.clone() },
+        };
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loads the snapshot to resume a game from, and updates the game's clients to match it.
+///
+/// Returns `None` if there is no usable snapshot, in which case a new game should be started.
+#[cfg(not(target_family = "wasm"))]
+fn load_resume_snapshot(game_id: u64, clients: &mut [ClientGameInit], game_data: &GameData)
+    -> Option<GameSnapshot>
+{
+    let snapshot = match GameSnapshot::load(&game_data.snapshot_settings.directory, game_id) {
+        Ok(snapshot) => snapshot?,
+        Err(err) => {
+            tracing::warn!("ignoring snapshot for game {game_id}: {err}");
+            return None;
+        }
+    };
+    let mut remapped = clients.to_vec();
+    if let Err(err) = snapshot
+        .validate(game_data)
+        .and_then(|_| remap_resumed_clients(&mut remapped, &snapshot))
+    {
+        tracing::warn!("ignoring snapshot for game {game_id}: {err}");
+        return None;
+    }
+    clients.clone_from_slice(&remapped);
+
+    Some(snapshot)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Configuration for setting up a game with a game factory.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProvGameFactoryConfig
@@ -129,7 +247,16 @@ pub enum ClientTypeInfo
     Player
     {
         player_name: String
-    }, // Watcher,
+    },
+    /// A player controlled by the server.
+    ///
+    /// The connection type and user id in [`ClientGameInit`] are ignored for bots.
+    Bot
+    {
+        difficulty: BotDifficulty
+    },
+    /// A client that watches the game without playing.
+    Watcher,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -161,6 +288,20 @@ pub struct LaunchData
 
     /// Client init data for use in initializing a game.
     pub clients: Vec<ClientGameInit>,
+
+    /// If set, the game resumes from the latest snapshot saved for its game id (if one exists).
+    ///
+    /// Clients are given the client ids they had in the snapshot. Only set when a game instance is relaunched
+    /// after dying mid-game, since game ids are reused and old snapshots may belong to a different game.
+    pub resume: bool,
+
+    /// If set, the game saves snapshots so it can be resumed if its instance dies.
+    ///
+    /// Only useful for games hosted by a game hub that can relaunch them. Ignored on WASM.
+    pub snapshots: bool,
+
+    /// Game rules set by the lobby.
+    pub rules: GameRuleOverrides,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -215,8 +356,59 @@ impl GameFactoryImpl for ProvGameFactory
             #[cfg(target_family = "wasm")]
             &self.configs
         };
-        let config = extract_game_configs(data.config, configs)?;
-        let startup = prepare_game_startup(game_id, &config.game_fw_config, data.clients, config.duration_config)?;
+        let mut config = extract_game_configs(data.config, configs)?;
+        let num_players = data
+            .clients
+            .iter()
+            .filter(|client| !matches!(client.client_type, ClientTypeInfo::Watcher))
+            .count();
+        data.rules
+            .apply(&mut config.duration_config, &mut config.game_data, num_players)?;
+        #[cfg(target_family = "wasm")]
+        let snapshots = false;
+        #[cfg(not(target_family = "wasm"))]
+        let snapshots = data.snapshots;
+        config.game_data.snapshot_settings.enabled &= snapshots;
+
+        // find the snapshot to resume from
+        #[allow(unused_mut)]
+        let mut clients = data.clients;
+        #[cfg(not(target_family = "wasm"))]
+        let snapshot = match data.resume {
+            true => load_resume_snapshot(game_id, &mut clients, &config.game_data),
+            // Clear snapshots left by an earlier game with the same id so a relaunch can't resume them.
+            false => {
+                if let Err(err) = GameSnapshot::remove(&config.game_data.snapshot_settings.directory, game_id) {
+                    tracing::warn!("failed clearing old snapshot for game {game_id}: {err}");
+                }
+                None
+            }
+        };
+        #[cfg(target_family = "wasm")]
+        let snapshot = None;
+
+        let startup = prepare_game_startup(
+            game_id,
+            &config.game_fw_config,
+            clients,
+            config.duration_config,
+            config.game_data.mapgen_settings.map_dimension,
+            snapshot,
+        )?;
+
+        // Make sure new games can place a start region for each player before starting them.
+        if startup.prov_init.snapshot.is_none() {
+            let seed = startup.prov_init.game_context.seed;
+            let tiles = generate_map_tiles(
+                map_gen_prng(seed),
+                &config.game_data.mapgen_settings,
+                &config.game_data.tiles,
+            );
+            config
+                .game_data
+                .start_region_settings
+                .check_map(&tiles, &config.game_data.tiles, num_players)?;
+        }
 
         // girk server config
         let server_config = GirkServerConfig {
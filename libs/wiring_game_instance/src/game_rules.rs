@@ -0,0 +1,65 @@
+use game_core::*;
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game rules that can be overridden per game. Rules that aren't set use the values from the game config.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GameRuleOverrides
+{
+    /// Overrides [`MapGenSettings::map_dimension`].
+    pub map_dimension: Option<i32>,
+    /// Overrides [`GameDurationConfig::num_rounds`].
+    pub num_rounds: Option<u32>,
+    /// Overrides [`GameDurationConfig::round_duration_ms`].
+    pub round_duration_ms: Option<u64>,
+    /// Overrides [`GameDurationConfig::tile_select_duration_ms`].
+    pub tile_select_duration_ms: Option<u64>,
+}
+
+impl GameRuleOverrides
+{
+    /// Applies the overrides to the game's configs.
+    ///
+    /// Returns an error if the overridden configs are invalid, or if the map doesn't have room for `num_players`
+    /// start regions.
+    pub fn apply(
+        &self,
+        duration_config: &mut GameDurationConfig,
+        game_data: &mut GameData,
+        num_players: usize,
+    ) -> Result<(), String>
+    {
+        let mapgen_settings = &mut game_data.mapgen_settings;
+        if let Some(map_dimension) = self.map_dimension {
+            if map_dimension <= mapgen_settings.edge_buffer as i32 {
+                return Err(format!("map dimension override {map_dimension} is not larger than the edge buffer"));
+            }
+            mapgen_settings.map_dimension = map_dimension;
+        }
+        if let Some(num_rounds) = self.num_rounds {
+            if num_rounds == 0 {
+                return Err("num rounds override is zero".into());
+            }
+            duration_config.num_rounds = num_rounds;
+        }
+        if let Some(round_duration_ms) = self.round_duration_ms {
+            if round_duration_ms == 0 {
+                return Err("round duration override is zero".into());
+            }
+            duration_config.round_duration_ms = round_duration_ms;
+        }
+        if let Some(tile_select_duration_ms) = self.tile_select_duration_ms {
+            duration_config.tile_select_duration_ms = tile_select_duration_ms;
+        }
+
+        game_data.validate()?;
+        game_data
+            .start_region_settings
+            .check_fits(&game_data.mapgen_settings, num_players)?;
+
+        Ok(())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -3,6 +3,7 @@ mod client_initializer;
 mod game_app_setup;
 mod game_config;
 mod game_factory;
+mod game_rules;
 mod protocol_id;
 
 pub use client_context::*;
@@ -10,4 +11,5 @@ pub use client_initializer::*;
 pub use game_app_setup::*;
 pub use game_config::*;
 pub use game_factory::*;
+pub use game_rules::*;
 pub use protocol_id::*;